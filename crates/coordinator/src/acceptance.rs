@@ -0,0 +1,124 @@
+use hdk::prelude::*;
+use hc_integrity_zome_invitations::*;
+use crate::invite::{get_invitation_info, get_latest_record};
+
+//the invitee countersigns an acceptance with the author of the invitation, the session is run
+//by the invitee over remote calls and commits the same Acceptance entry on both chains.
+//a countersigned entry has to be the only write of its zome call, so the UI calls this first,
+//waits for the session to complete and then gives the returned hash to accept_invitation
+#[hdk_extern]
+pub fn countersign_acceptance(creation_hash: ActionHash) -> ExternResult<ActionHash> {
+    let my_pub_key: AgentPubKey = agent_info()?.agent_latest_pubkey;
+    let entry_info = get_invitation_info(&creation_hash)?;
+    if !entry_info.invitation.countersign_acceptances {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from("This invitation does not countersign its acceptances"))));
+    }
+    let acceptance = Acceptance {
+        creation_hash: creation_hash.clone(),
+        invite_action_hash: get_latest_record(creation_hash)?.action_address().clone(),
+        invitee: my_pub_key.clone(),
+        inviter: entry_info.author.clone(),
+    };
+    let preflight_request = PreflightRequest::try_new(
+        hash_entry(&acceptance)?,
+        vec![(my_pub_key, vec![]), (entry_info.author.clone(), vec![])],
+        vec![],
+        0,
+        false,
+        session_times_from_millis(10_000)?,
+        ActionBase::Create(CreateBase::new(UnitEntryTypes::Acceptance.try_into()?)),
+        PreflightBytes(vec![]),
+    ).map_err(|err| wasm_error!(WasmErrorInner::Guest(err.to_string())))?;
+
+    let my_response = accept_preflight_request(preflight_request.clone())?;
+    let inviter_response: PreflightResponse = call_inviter(
+        &entry_info.author,
+        "accept_acceptance_preflight",
+        AcceptancePreflightInput { acceptance: acceptance.clone(), preflight_request },
+    )?;
+    let responses = vec![my_response, inviter_response];
+    let acceptance_hash = create_countersigned_acceptance(&acceptance, responses.clone())?;
+    call_inviter::<ActionHash, _>(
+        &entry_info.author,
+        "commit_countersigned_acceptance",
+        CountersignedAcceptanceInput { acceptance, responses },
+    )?;
+    Ok(acceptance_hash)
+}
+
+
+//called remotely by an invitee, the author checks the acceptance before joining the session
+#[hdk_extern]
+pub fn accept_acceptance_preflight(input: AcceptancePreflightInput) -> ExternResult<PreflightResponse> {
+    check_acceptance(&input.acceptance)?;
+    if hash_entry(&input.acceptance)? != input.preflight_request.app_entry_hash {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from("The preflight request is not for this acceptance"))));
+    }
+    accept_preflight_request(input.preflight_request)
+}
+
+
+//called remotely by an invitee once both agents joined the session
+#[hdk_extern]
+pub fn commit_countersigned_acceptance(input: CountersignedAcceptanceInput) -> ExternResult<ActionHash> {
+    check_acceptance(&input.acceptance)?;
+    create_countersigned_acceptance(&input.acceptance, input.responses)
+}
+
+
+
+
+//************ Helpers **************************
+
+//the author only countersigns acceptances of their own invitations made by the calling invitee
+fn check_acceptance(acceptance: &Acceptance) -> ExternResult<()> {
+    let my_pub_key: AgentPubKey = agent_info()?.agent_latest_pubkey;
+    let caller = call_info()?.provenance;
+    let entry_info = get_invitation_info(&acceptance.creation_hash)?;
+    if acceptance.inviter != my_pub_key || entry_info.author != my_pub_key {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from("Only the author of an invitation countersigns its acceptances"))));
+    }
+    if acceptance.invitee != caller {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from("Invitees can only countersign their own acceptance"))));
+    }
+    if !entry_info.invitation.countersign_acceptances {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from("This invitation does not countersign its acceptances"))));
+    }
+    Ok(())
+}
+
+fn accept_preflight_request(preflight_request: PreflightRequest) -> ExternResult<PreflightResponse> {
+    match accept_countersigning_preflight_request(preflight_request)? {
+        PreflightRequestAcceptance::Accepted(response) => Ok(response),
+        _ => Err(wasm_error!(WasmErrorInner::Guest(String::from("The countersigning session could not be started")))),
+    }
+}
+
+fn create_countersigned_acceptance(acceptance: &Acceptance, responses: Vec<PreflightResponse>) -> ExternResult<ActionHash> {
+    let session_data = CounterSigningSessionData::try_from_responses(responses, vec![])
+        .map_err(|err| wasm_error!(WasmErrorInner::Guest(err.to_string())))?;
+    let entry = EntryTypes::Acceptance(acceptance.clone());
+    let entry_def_index = ScopedEntryDefIndex::try_from(&entry)?;
+    let entry_visibility = EntryVisibility::from(&entry);
+    let entry_bytes = AppEntryBytes::try_from(SerializedBytes::try_from(acceptance.clone()).map_err(|e| wasm_error!(e))?)
+        .map_err(|err| wasm_error!(WasmErrorInner::Guest(err.to_string())))?;
+    HDK.with(|h| {
+        h.borrow().create(CreateInput::new(
+            EntryDefLocation::app(entry_def_index.zome_index, entry_def_index.zome_type),
+            entry_visibility,
+            Entry::CounterSign(Box::new(session_data), entry_bytes),
+            ChainTopOrdering::Strict,
+        ))
+    })
+}
+
+fn call_inviter<O, I>(inviter: &AgentPubKey, fn_name: &str, payload: I) -> ExternResult<O>
+where
+    O: std::fmt::Debug + serde::de::DeserializeOwned,
+    I: serde::Serialize + std::fmt::Debug,
+{
+    match call_remote(inviter.clone(), zome_info()?.name, fn_name.into(), None, payload)? {
+        ZomeCallResponse::Ok(result) => result.decode().map_err(|e| wasm_error!(e)),
+        other => Err(wasm_error!(WasmErrorInner::Guest(format!("The author of the invitation could not countersign: {:?}", other)))),
+    }
+}
@@ -0,0 +1,140 @@
+use hdk::prelude::*;
+use hc_integrity_zome_invitations::*;
+use crate::invite::{get_invitation_info, get_invite_hosts, get_latest_record};
+use crate::invite_code::get_invite_redeemers;
+
+//invitees check in while the invitation, or an occurrence of a recurring one, takes place
+//with the token of a host when the invitation requires one
+#[hdk_extern]
+pub fn check_in(input: CheckInInput) -> ExternResult<InviteInfo> {
+    let my_pub_key: AgentPubKey = agent_info()?.agent_latest_pubkey;
+    let entry_info = get_invitation_info(&input.creation_hash)?;
+    if entry_info.cancelled {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from("This invitation has been cancelled"))));
+    }
+    let start_time = entry_info.invitation.start_time.ok_or(wasm_error!(
+        WasmErrorInner::Guest(String::from("This invitation has no start time to check in to"))
+    ))?;
+    let now = sys_time()?;
+    //without an occurrence a recurring invitation is checked in to the one that started last
+    let occurrence = if entry_info.invitation.recurrence.is_some() && input.occurrence.is_none() {
+        expand_occurrences(&entry_info.invitation, start_time, now).last().cloned()
+    } else {
+        input.occurrence
+    };
+    let (occurrence_start, occurrence_end) = get_check_in_window(&entry_info.invitation, occurrence).ok_or(wasm_error!(
+        WasmErrorInner::Guest(String::from("This invitation does not recur at that time"))
+    ))?;
+    if now < occurrence_start || occurrence_end.is_some_and(|end_time| now > end_time) {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from("You can only check in while the invitation takes place"))));
+    }
+    let checked_in = get_check_ins(&entry_info.creation_hash, &entry_info.author, &entry_info.invitation)?;
+    if checked_in.contains(&(my_pub_key.clone(), occurrence)) {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from("You have already checked in"))));
+    }
+    if entry_info.invitation.require_check_in_token && input.token.is_none() {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from("This invitation requires a check-in token from a host"))));
+    }
+    //agents that joined with the invite code refer to their redemption
+    let redemption_hash = if entry_info.invitation.invitees.contains(&my_pub_key) {
+        None
+    } else {
+        Some(
+            get_invite_redeemers(&entry_info.creation_hash, &entry_info.invitation)?
+                .into_iter()
+                .find(|(redeemer, _)| redeemer == &my_pub_key)
+                .map(|(_, redemption_hash)| redemption_hash)
+                .ok_or(wasm_error!(WasmErrorInner::Guest(String::from("Only invitees can check in to this invitation"))))?
+        )
+    };
+    let last_invite_record = get_latest_record(input.creation_hash.clone())?;
+    let check_in_tag = CheckInTag {
+        invite_action_hash: last_invite_record.action_address().clone(),
+        token: input.token,
+        redemption_hash,
+        occurrence,
+    };
+    create_link(
+        input.creation_hash.clone(),
+        my_pub_key,
+        LinkTypes::InviteToCheckIn,
+        LinkTag::try_from(check_in_tag)?,
+    )?;
+    get_invitation_info(&input.creation_hash)
+}
+
+
+//a host signs a token that lets one invitee check in, the UI passes it on to the invitee
+#[hdk_extern]
+pub fn issue_check_in_token(input: IssueCheckInTokenInput) -> ExternResult<CheckInToken> {
+    let my_pub_key: AgentPubKey = agent_info()?.agent_latest_pubkey;
+    let entry_info = get_invitation_info(&input.creation_hash)?;
+    if !get_invite_hosts(&entry_info.author, &entry_info.invitation).contains(&my_pub_key) {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from("Only hosts can issue check-in tokens"))));
+    }
+    if !entry_info.invitation.invitees.contains(&input.invitee) && !entry_info.invitees_redeemed.contains(&input.invitee) {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from("Check-in tokens can only be issued to invitees"))));
+    }
+    let token_data = CheckInTokenData {
+        creation_hash: input.creation_hash,
+        invitee: input.invitee,
+    };
+    Ok(CheckInToken {
+        signature: sign(my_pub_key.clone(), token_data)?,
+        host: my_pub_key,
+    })
+}
+
+
+
+
+//************ Helpers **************************
+
+//invitees that checked in to any occurrence, in the order they did
+pub fn get_checked_in_invitees(create_action_hash: &ActionHash, author: &AgentPubKey, invite: &Invite) -> ExternResult<Vec<AgentPubKey>> {
+    let mut checked_in: Vec<AgentPubKey> = vec![];
+    for (invitee, _) in get_check_ins(create_action_hash, author, invite)?.into_iter() {
+        if !checked_in.contains(&invitee) {
+            checked_in.push(invitee);
+        }
+    }
+    Ok(checked_in)
+}
+
+//invitees that checked in to one occurrence of a recurring invitation
+pub fn get_occurrence_checked_in_invitees(create_action_hash: &ActionHash, author: &AgentPubKey, invite: &Invite, occurrence: Timestamp) -> ExternResult<Vec<AgentPubKey>> {
+    Ok(get_check_ins(create_action_hash, author, invite)?
+        .into_iter()
+        .filter(|(_, checked_in_occurrence)| checked_in_occurrence == &Some(occurrence))
+        .map(|(invitee, _)| invitee)
+        .collect())
+}
+
+//every check-in with its occurrence, in the order they were made. Validation checked them against the version they cite,
+//so check-ins outside the current time of the invitation or without the token it now requires are left out
+fn get_check_ins(create_action_hash: &ActionHash, author: &AgentPubKey, invite: &Invite) -> ExternResult<Vec<(AgentPubKey, Option<Timestamp>)>> {
+    let mut links = get_links(create_action_hash.clone(), LinkTypes::InviteToCheckIn, None)?;
+    links.sort_by(|a, b| a.timestamp.cmp(&b.timestamp).then(a.create_link_hash.cmp(&b.create_link_hash)));
+    let hosts = get_invite_hosts(author, invite);
+    let mut check_ins: Vec<(AgentPubKey, Option<Timestamp>)> = vec![];
+    for link in links.into_iter() {
+        let check_in_tag = match CheckInTag::try_from(link.tag) {
+            Ok(check_in_tag) => check_in_tag,
+            Err(_) => continue,
+        };
+        let in_window = match get_check_in_window(invite, check_in_tag.occurrence) {
+            Some((start_time, Some(end_time))) => link.timestamp >= start_time && link.timestamp <= end_time,
+            Some((start_time, None)) => link.timestamp >= start_time,
+            None => false,
+        };
+        let has_token = match &check_in_tag.token {
+            Some(token) => hosts.contains(&token.host),
+            None => !invite.require_check_in_token,
+        };
+        if in_window && has_token {
+            let invitee = AgentPubKey::try_from(link.target).map_err(|err| wasm_error!(err))?;
+            check_ins.push((invitee, check_in_tag.occurrence));
+        }
+    }
+    Ok(check_ins)
+}
@@ -0,0 +1,128 @@
+use std::collections::BTreeMap;
+use hdk::prelude::*;
+use hc_integrity_zome_invitations::*;
+use crate::invite::get_invitation_info;
+use crate::utils::query_my_latest_records;
+use crate::signals::{emit_to_ui, Signal};
+
+//notices that could not be delivered when the invitation was committed are retried every minute
+pub const NOTICE_RETRY_SCHEDULE: &str = "0 * * * * * *";
+
+//called remotely by a host, returning acknowledges the delivery of the invitation
+#[hdk_extern]
+pub fn receive_invitation_notice(signal: Signal) -> ExternResult<()> {
+    emit_to_ui(signal, false)?;
+    Ok(())
+}
+
+
+//scheduled on init, it keeps its schedule by returning it
+#[hdk_extern(infallible)]
+pub fn retry_invitation_notices(_: Option<Schedule>) -> Option<Schedule> {
+    if let Err(err) = retry_undelivered_notices() {
+        error!("Error retrying invitation notices: {:?}", err);
+    }
+    Some(Schedule::Persisted(NOTICE_RETRY_SCHEDULE.into()))
+}
+
+
+
+
+//************ Helpers **************************
+
+//the invitation is sent to every recipient straight away, the ones that did not acknowledge it
+//are left as undelivered notices for retry_invitation_notices
+pub fn deliver_invitation_notices(signal: &Signal, action_hash: &ActionHash, creation_hash: &ActionHash, recipients: &[AgentPubKey]) -> ExternResult<()> {
+    for recipient in recipients.iter() {
+        let invitation_notice = InvitationNotice {
+            creation_hash: creation_hash.clone(),
+            action_hash: action_hash.clone(),
+            recipient: recipient.clone(),
+            delivered: send_invitation_notice(recipient, signal)?,
+        };
+        create_entry(&EntryTypes::InvitationNotice(invitation_notice))?;
+    }
+    Ok(())
+}
+
+//agents that are offline or unreachable do not acknowledge
+fn send_invitation_notice(recipient: &AgentPubKey, signal: &Signal) -> ExternResult<bool> {
+    let response = call_remote(
+        recipient.clone(),
+        zome_info()?.name,
+        "receive_invitation_notice".into(),
+        None,
+        signal,
+    );
+    Ok(matches!(response, Ok(ZomeCallResponse::Ok(_))))
+}
+
+//the invitation is sent as it is now, notices of cancelled invitations or for agents that are no longer invited are dropped
+fn retry_undelivered_notices() -> ExternResult<()> {
+    let mut delivered: BTreeMap<ActionHash, Vec<AgentPubKey>> = BTreeMap::new();
+    let mut invitations: BTreeMap<ActionHash, InviteInfo> = BTreeMap::new();
+    for (_, record) in query_my_latest_records(UnitEntryTypes::InvitationNotice.try_into()?)?.into_iter() {
+        let mut invitation_notice: InvitationNotice = match record.entry().to_app_option().map_err(|e| wasm_error!(e))? {
+            Some(invitation_notice) => invitation_notice,
+            None => continue,
+        };
+        if invitation_notice.delivered {
+            continue;
+        }
+        //a later notice of the same invitation may have reached the recipient already
+        if !delivered.contains_key(&invitation_notice.creation_hash) {
+            delivered.insert(invitation_notice.creation_hash.clone(), get_delivered_invitees(&invitation_notice.creation_hash)?.unwrap_or_default());
+        }
+        if delivered[&invitation_notice.creation_hash].contains(&invitation_notice.recipient) {
+            continue;
+        }
+        if !invitations.contains_key(&invitation_notice.creation_hash) {
+            invitations.insert(invitation_notice.creation_hash.clone(), get_invitation_info(&invitation_notice.creation_hash)?);
+        }
+        let invite_detail = invitations[&invitation_notice.creation_hash].clone();
+        if invite_detail.cancelled
+            || (!invite_detail.invitation.invitees.contains(&invitation_notice.recipient)
+                && !invite_detail.invitees_redeemed.contains(&invitation_notice.recipient)) {
+            continue;
+        }
+        let action = match get(invitation_notice.action_hash.clone(), GetOptions::default())? {
+            Some(action_record) => action_record.signed_action().clone(),
+            None => continue,
+        };
+        let signal = Signal::InvitationReceived {
+            action,
+            data: invite_detail,
+        };
+        if send_invitation_notice(&invitation_notice.recipient, &signal)? {
+            if let Some(recipients) = delivered.get_mut(&invitation_notice.creation_hash) {
+                recipients.push(invitation_notice.recipient.clone());
+            }
+            invitation_notice.delivered = true;
+            update_entry(record.action_address().clone(), &EntryTypes::InvitationNotice(invitation_notice))?;
+        }
+    }
+    Ok(())
+}
+
+//recipients that acknowledged a notice of the invitation, only the hosts that sent them know about it
+//so it is None for everyone else
+pub fn get_delivered_invitees(create_action_hash: &ActionHash) -> ExternResult<Option<Vec<AgentPubKey>>> {
+    let mut is_sender = false;
+    let mut delivered: Vec<AgentPubKey> = vec![];
+    for (_, record) in query_my_latest_records(UnitEntryTypes::InvitationNotice.try_into()?)?.into_iter() {
+        if let Some(invitation_notice) = record.entry().to_app_option::<InvitationNotice>().map_err(|e| wasm_error!(e))? {
+            if &invitation_notice.creation_hash != create_action_hash {
+                continue;
+            }
+            is_sender = true;
+            if invitation_notice.delivered && !delivered.contains(&invitation_notice.recipient) {
+                delivered.push(invitation_notice.recipient);
+            }
+        }
+    }
+    if is_sender {
+        Ok(Some(delivered))
+    } else {
+        Ok(None)
+    }
+}
@@ -0,0 +1,109 @@
+use hdk::prelude::*;
+use hc_integrity_zome_invitations::*;
+use crate::invite::get_invite_hosts;
+
+//agents publish their encryption key once, it is also done on init
+#[hdk_extern]
+pub fn publish_encryption_key(_: ()) -> ExternResult<X25519PubKey> {
+    let my_pub_key: AgentPubKey = agent_info()?.agent_latest_pubkey;
+    if let Some(key) = get_encryption_key(&my_pub_key)? {
+        return Ok(key);
+    }
+    let key = create_x25519_keypair()?;
+    let key_hash = create_entry(&EntryTypes::EncryptionKey(EncryptionKey { key }))?;
+    create_link(
+        my_pub_key,
+        key_hash,
+        LinkTypes::AgentToEncryptionKey,
+        (),
+    )?;
+    Ok(key)
+}
+
+
+
+
+//************ Helpers **************************
+
+//the first key an agent published
+pub fn get_encryption_key(agent: &AgentPubKey) -> ExternResult<Option<X25519PubKey>> {
+    let mut links = get_links(agent.clone(), LinkTypes::AgentToEncryptionKey, None)?;
+    links.sort_by(|a, b| a.timestamp.cmp(&b.timestamp).then(a.create_link_hash.cmp(&b.create_link_hash)));
+    let link = match links.into_iter().next() {
+        Some(link) => link,
+        None => return Ok(None),
+    };
+    let key_hash = ActionHash::try_from(link.target).map_err(|err| wasm_error!(err))?;
+    let record = get(key_hash, GetOptions::default())?
+    .ok_or(wasm_error!(WasmErrorInner::Guest(String::from("Could not find the encryption key record"))))?;
+    let encryption_key: EncryptionKey = record.entry().to_app_option().map_err(|e| wasm_error!(e))?
+    .ok_or(wasm_error!(WasmErrorInner::Guest(String::from("Could not de-serialize the EncryptionKey Entry in the record"))))?;
+    Ok(Some(encryption_key.key))
+}
+
+//moves the location and details of the invitation into a box for every host and invitee,
+//so that only they can read them. Every recipient must have published an encryption key
+pub fn encrypt_invite_details(author: &AgentPubKey, mut invite: Invite, invitees: &[AgentPubKey]) -> ExternResult<Invite> {
+    let sender_key = publish_encryption_key(())?;
+    let private_details = PrivateInviteDetails {
+        location: invite.location.take(),
+        details: invite.details.take(),
+    };
+    let data = XSalsa20Poly1305Data::from(
+        SerializedBytes::try_from(private_details).map_err(|e| wasm_error!(e))?.bytes().clone()
+    );
+    let mut recipients: Vec<AgentPubKey> = get_invite_hosts(author, &invite);
+    for invitee in invitees.iter() {
+        if !recipients.contains(invitee) {
+            recipients.push(invitee.clone());
+        }
+    }
+    let mut encrypted_recipients: Vec<EncryptedDetailsRecipient> = vec![];
+    for agent in recipients.into_iter() {
+        let recipient_key = get_encryption_key(&agent)?.ok_or(wasm_error!(WasmErrorInner::Guest(
+            format!("{} has not published an encryption key yet", agent)
+        )))?;
+        encrypted_recipients.push(EncryptedDetailsRecipient {
+            agent,
+            data: x_25519_x_salsa20_poly1305_encrypt(sender_key, recipient_key, data.clone())?,
+        });
+    }
+    invite.encrypted_details = Some(EncryptedDetails {
+        sender_key,
+        recipients: encrypted_recipients,
+    });
+    Ok(invite)
+}
+
+//the invitation as anyone but its recipients sees it, for signals to agents that are no longer one of them
+pub fn without_decrypted_details(mut invite: Invite) -> Invite {
+    if invite.encrypted_details.is_some() {
+        invite.location = None;
+        invite.details = None;
+    }
+    invite
+}
+
+//fills in the location and details for a recipient of the invitation, anyone else only sees the encrypted details
+pub fn decrypt_invite_details(mut invite: Invite) -> ExternResult<Invite> {
+    let encrypted_details = match &invite.encrypted_details {
+        Some(encrypted_details) => encrypted_details,
+        None => return Ok(invite),
+    };
+    let my_pub_key: AgentPubKey = agent_info()?.agent_latest_pubkey;
+    let recipient = match encrypted_details.recipients.iter().find(|recipient| recipient.agent == my_pub_key) {
+        Some(recipient) => recipient,
+        None => return Ok(invite),
+    };
+    let my_key = match get_encryption_key(&my_pub_key)? {
+        Some(my_key) => my_key,
+        None => return Ok(invite),
+    };
+    if let Some(data) = x_25519_x_salsa20_poly1305_decrypt(my_key, encrypted_details.sender_key, recipient.data.clone())? {
+        let private_details = PrivateInviteDetails::try_from(SerializedBytes::from(UnsafeBytes::from(data.as_ref().to_vec())))
+            .map_err(|e| wasm_error!(e))?;
+        invite.location = private_details.location;
+        invite.details = private_details.details;
+    }
+    Ok(invite)
+}
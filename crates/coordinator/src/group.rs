@@ -0,0 +1,140 @@
+use hdk::prelude::*;
+use hc_integrity_zome_invitations::*;
+use crate::invite::{get_invitation_info, get_invite_hosts, get_latest_record, update_invite_entry};
+use crate::utils::query_my_latest_records;
+
+#[hdk_extern]
+pub fn create_group(group: Group) -> ExternResult<GroupInfo> {
+    let group_hash = create_entry(&EntryTypes::Group(group))?;
+    get_group(group_hash)
+}
+
+
+#[hdk_extern]
+pub fn get_group(group_hash: ActionHash) -> ExternResult<GroupInfo> {
+    let (author, deleted) = match get_details(group_hash.clone(), GetOptions::default())? {
+        Some(Details::Record(record_details)) => (record_details.record.action().author().clone(), !record_details.deletes.is_empty()),
+        _ => return Err(wasm_error!(WasmErrorInner::Guest(String::from("Could not find the group")))),
+    };
+    if deleted {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from("This group has been deleted"))));
+    }
+    let record = get_latest_record(group_hash.clone())?;
+    Ok(GroupInfo {
+        group: get_group_entry(&record)?,
+        group_hash,
+        author,
+        timestamp: record.action().timestamp(),
+    })
+}
+
+
+//groups we created, they are public entries but also live on our own source chain
+#[hdk_extern]
+pub fn get_my_groups(_: ()) -> ExternResult<Vec<GroupInfo>> {
+    let my_pub_key: AgentPubKey = agent_info()?.agent_latest_pubkey;
+    let mut groups: Vec<GroupInfo> = vec![];
+    for (group_hash, record) in query_my_latest_records(UnitEntryTypes::Group.try_into()?)?.into_iter() {
+        groups.push(GroupInfo {
+            group: get_group_entry(&record)?,
+            group_hash,
+            author: my_pub_key.clone(),
+            timestamp: record.action().timestamp(),
+        });
+    }
+    Ok(groups)
+}
+
+
+//with propagate, members added to or removed from the group are added to or removed from
+//the open invitations that referenced it and that we host
+#[hdk_extern]
+pub fn update_group(input: UpdateGroupInput) -> ExternResult<GroupInfo> {
+    let previous_group = get_group(input.group_hash.clone())?.group;
+    let latest_record = get_latest_record(input.group_hash.clone())?;
+    update_entry(latest_record.action_address().clone(), &EntryTypes::Group(input.group.clone()))?;
+    if input.propagate {
+        propagate_group_changes(&input.group_hash, &previous_group, &input.group)?;
+    }
+    get_group(input.group_hash)
+}
+
+
+//invitations made from the group keep their invitees
+#[hdk_extern]
+pub fn delete_group(group_hash: ActionHash) -> ExternResult<ActionHash> {
+    delete_entry(group_hash)
+}
+
+
+
+
+//************ Helpers **************************
+
+fn get_group_entry(record: &Record) -> ExternResult<Group> {
+    record.entry().to_app_option().map_err(|e| wasm_error!(e))?
+    .ok_or(wasm_error!(WasmErrorInner::Guest(String::from("Could not de-serialize the Group Entry in the record"))))
+}
+
+//the invitees of an invitation together with the current members of its groups, the author is never their own invitee
+pub fn expand_groups(author: &AgentPubKey, invitees: &[AgentPubKey], groups: &[ActionHash]) -> ExternResult<Vec<AgentPubKey>> {
+    let mut expanded: Vec<AgentPubKey> = invitees.to_vec();
+    for group_hash in groups.iter() {
+        for member in get_group(group_hash.clone())?.group.members.into_iter() {
+            if !expanded.contains(&member) && &member != author {
+                expanded.push(member);
+            }
+        }
+    }
+    Ok(expanded)
+}
+
+//lets the groups find the invitations that referenced them, from the invite version that added them
+pub fn link_groups(create_action_hash: &ActionHash, invite_action_hash: &ActionHash, groups: &[ActionHash]) -> ExternResult<()> {
+    for group_hash in groups.iter() {
+        create_link(
+            group_hash.clone(),
+            create_action_hash.clone(),
+            LinkTypes::GroupToInvite,
+            group_link_tag(invite_action_hash),
+        )?;
+    }
+    Ok(())
+}
+
+//invitations that are cancelled, closed, expired or that dropped the group are left as they are
+fn propagate_group_changes(group_hash: &ActionHash, previous_group: &Group, updated_group: &Group) -> ExternResult<()> {
+    let my_pub_key: AgentPubKey = agent_info()?.agent_latest_pubkey;
+    let mut invite_hashes: Vec<ActionHash> = vec![];
+    for link in get_links(group_hash.clone(), LinkTypes::GroupToInvite, None)?.into_iter() {
+        let invite_hash = ActionHash::try_from(link.target).map_err(|err| wasm_error!(err))?;
+        if !invite_hashes.contains(&invite_hash) {
+            invite_hashes.push(invite_hash);
+        }
+    }
+    for invite_hash in invite_hashes.into_iter() {
+        let entry_info = get_invitation_info(&invite_hash)?;
+        let mut invite = entry_info.invitation;
+        if entry_info.cancelled || entry_info.expired || invite.closed
+            || !invite.groups.contains(group_hash)
+            || !get_invite_hosts(&entry_info.author, &invite).contains(&my_pub_key) {
+            continue;
+        }
+        //members removed from the group leave the invitation and added ones join it
+        let mut invitees: Vec<AgentPubKey> = invite.invitees.iter()
+            .filter(|invitee| !previous_group.members.contains(invitee) || updated_group.members.contains(invitee))
+            .cloned()
+            .collect();
+        for member in updated_group.members.iter().filter(|member| !previous_group.members.contains(member)) {
+            if !invitees.contains(member) && member != &entry_info.author {
+                invitees.push(member.clone());
+            }
+        }
+        if invitees != invite.invitees {
+            invite.invitee_roles.retain(|invitee_role| invitees.contains(&invitee_role.agent));
+            invite.invitees = invitees;
+            update_invite_entry(invite_hash, invite)?;
+        }
+    }
+    Ok(())
+}
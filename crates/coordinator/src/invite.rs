@@ -1,39 +1,54 @@
 use hdk::prelude::*;
 use hc_integrity_zome_invitations::*;
+use std::collections::BTreeMap;
+use crate::invite_code::{get_invite_redeemers, get_invite_with_redeemers};
+use crate::group::{expand_groups, link_groups};
+use crate::slot_poll::get_slot_tallies;
+use crate::encryption::{decrypt_invite_details, encrypt_invite_details};
+use crate::check_in::get_checked_in_invitees;
+use crate::delivery::get_delivered_invitees;
 
 #[hdk_extern]
-fn create_invitation(input: InviteInput) -> ExternResult<InviteInfo> {
+pub fn create_invitation(input: InviteInput) -> ExternResult<InviteInfo> {
   let my_pub_key: AgentPubKey = agent_info()?.agent_latest_pubkey;
 
+  //the members of the groups are invited along with the listed invitees
+  let invitees = expand_groups(&my_pub_key, &input.invitees, &input.groups)?;
   let invitation = Invite {
-      invitees: input.invitees.clone(),
+      invitees: invitees.clone(),
+      invitee_roles: input.invitee_roles,
+      co_hosts: input.co_hosts,
       location: input.location,
       start_time: input.start_time,
       end_time: input.end_time,
       details: input.details,
+      rsvp_deadline: input.rsvp_deadline,
+      max_attendees: input.max_attendees,
+      min_attendees: input.min_attendees,
+      allow_guests: input.allow_guests,
+      max_guests_per_invitee: input.max_guests_per_invitee,
+      recurrence: input.recurrence,
+      invite_code: input.invite_code,
+      groups: input.groups.clone(),
+      slots: input.slots,
+      encrypted_details: None,
+      countersign_acceptances: input.countersign_acceptances,
+      require_check_in_token: input.require_check_in_token,
+      closed: false,
+    };
+    let invitation = if input.encrypt_details.unwrap_or(false) {
+        encrypt_invite_details(&my_pub_key, invitation, &invitees)?
+    } else {
+        invitation
     };
 
     let action_hash = create_entry(&EntryTypes::Invite(invitation.clone()))?;
+    link_invitees(&action_hash, &invitees)?;
+    link_groups(&action_hash, &action_hash, &input.groups)?;
 
-   for agent in input.invitees.clone().into_iter(){
-        create_link(
-            agent,
-            action_hash.clone(),
-            LinkTypes::AgentToInvite,
-            LinkTag::new(String::from("pending")),
-        )?;
-    }
-
-    //creator of the invitation is not an invitee
-    if !input.invitees.contains(&my_pub_key){
-        create_link(
-            my_pub_key.clone(),
-            action_hash.clone(),
-            LinkTypes::AgentToInvite,
-            LinkTag::new(String::from("inviter")),
-        )?;
-    }
-    return Ok(get_invitation_info(&action_hash)?);
+    //hosts of the invitation that are not invitees
+    link_inviters(&action_hash, &get_inviters(&my_pub_key, &invitation))?;
+    get_invitation_info(&action_hash)
 }
 
 
@@ -46,25 +61,61 @@ pub fn update_invitation(invitation: InviteInput) -> ExternResult<InviteInfo> {
             "Cannot find original action hash to update Invite entry".to_string()
         )))?
     };
-    let last_invite_record = get_latest_record(hash_result)?;
-
+    let author = get_invite_author(&hash_result)?;
+    let last_invite = get_latest_invite(&hash_result)?;
+    //only groups added by this update are expanded, membership changes of the others are left to propagate_group_changes
+    let added_groups: Vec<ActionHash> = invitation.groups.iter()
+        .filter(|group_hash| !last_invite.groups.contains(group_hash))
+        .cloned()
+        .collect();
     let updated_invite = Invite {
-        invitees: invitation.invitees.clone(), //change invitees?
+        invitees: expand_groups(&author, &invitation.invitees, &added_groups)?,
+        invitee_roles: invitation.invitee_roles,
+        co_hosts: invitation.co_hosts,
         location: invitation.location, 
         start_time: invitation.start_time,
         end_time: invitation.end_time,
         details: invitation.details,
+        rsvp_deadline: invitation.rsvp_deadline,
+        max_attendees: invitation.max_attendees,
+        min_attendees: invitation.min_attendees,
+        allow_guests: invitation.allow_guests,
+        max_guests_per_invitee: invitation.max_guests_per_invitee,
+        recurrence: invitation.recurrence,
+        invite_code: invitation.invite_code,
+        groups: invitation.groups,
+        slots: invitation.slots,
+        encrypted_details: None,
+        countersign_acceptances: invitation.countersign_acceptances,
+        require_check_in_token: invitation.require_check_in_token,
+        closed: last_invite.closed,
       };
-    let update_hash = update_entry(last_invite_record.action_address().clone(), &updated_invite)?;
-    return Ok(get_invitation_update_info(&update_hash)?);
+    let updated_invite = if invitation.encrypt_details.unwrap_or(last_invite.encrypted_details.is_some()) {
+        let invitees = get_invite_with_redeemers(&hash_result, &updated_invite)?.invitees;
+        encrypt_invite_details(&author, updated_invite, &invitees)?
+    } else {
+        updated_invite
+    };
+    update_invite_entry(hash_result, updated_invite)
 }
 
 
+//invitations that are closed or whose rsvp deadline has passed can no longer be answered and are left out.
+//with a role only the invitations in which we have that role are listed
 #[hdk_extern]
-pub fn get_my_pending_invitations(_: ()) -> ExternResult<Option<Vec<InviteInfo>>> {
+pub fn get_my_pending_invitations(role: Option<String>) -> ExternResult<Option<Vec<InviteInfo>>> {
     let agent: AgentPubKey = agent_info()?.agent_latest_pubkey;
-    let links = get_links(agent, LinkTypes::AgentToInvite, Some(LinkTag::new("pending")))?;
-    Ok(get_invite_info_from_links(links)?)
+    let links = get_links(agent.clone(), LinkTypes::AgentToInvite, Some(LinkTag::new("pending")))?;
+    let invitations = get_invite_info_from_links(links)?.map(|invitations| {
+        invitations.into_iter()
+            .filter(|invitation| !invitation.expired && !invitation.invitation.closed)
+            .filter(|invitation| match &role {
+                Some(role) => &get_invitee_role(&invitation.invitation, &agent).role == role,
+                None => true,
+            })
+            .collect::<Vec<InviteInfo>>()
+    });
+    Ok(invitations.filter(|invitations| !invitations.is_empty()))
 }
 
 
@@ -72,38 +123,61 @@ pub fn get_my_pending_invitations(_: ()) -> ExternResult<Option<Vec<InviteInfo>>
 pub fn get_all_my_invitations(_: ()) -> ExternResult<Option<Vec<InviteInfo>>> {
     let agent: AgentPubKey = agent_info()?.agent_latest_pubkey;
     let links = get_links(agent, LinkTypes::AgentToInvite,None)?;
-    Ok(get_invite_info_from_links(links)?)
+    get_invite_info_from_links(links)
 }
 
 
+//every response given to an invitation, oldest first. Only the latest response of each invitee counts
 #[hdk_extern]
-pub fn accept_invitation(original_action_hash: ActionHash) -> ExternResult<ActionHash> {
-    let my_pub_key: AgentPubKey = agent_info()?.agent_latest_pubkey;
-    let entry_info = get_invitation_info( &original_action_hash)?;
+pub fn get_invitation_response_history(original_action_hash: ActionHash) -> ExternResult<Vec<InviteResponse>> {
+    get_invite_response_history(&original_action_hash)
+}
 
-    create_link(
-        entry_info.creation_hash.clone(), //action hash
-        my_pub_key.clone(),
-        LinkTypes::InviteToAgent,
-        LinkTag::new(String::from("accepted")),
-    )?;
-    let committed_link_hash = commit_invitation(entry_info.creation_hash)?;
-    return Ok(committed_link_hash)
+
+#[hdk_extern]
+pub fn accept_invitation(input: AcceptInput) -> ExternResult<ActionHash> {
+    respond_to_invitation(input.creation_hash, "accepted", input.guests, input.note, input.reason, input.occurrence, input.acceptance_hash)
 }
 
 
 #[hdk_extern]
-pub fn reject_invitation(original_action_hash: ActionHash) -> ExternResult<ActionHash> {
+pub fn reject_invitation(input: RejectInput) -> ExternResult<ActionHash> {
+    respond_to_invitation(input.creation_hash, "rejected", 0, input.note, input.reason, input.occurrence, None)
+}
+
+
+#[hdk_extern]
+pub fn tentative_invitation(input: TentativeInput) -> ExternResult<ActionHash> {
+    respond_to_invitation(input.creation_hash, "tentative", 0, input.note, input.reason, input.occurrence, None)
+}
+
+//only the author can cancel, the Invite entry is deleted and every invitee is signalled from post_commit
+#[hdk_extern]
+pub fn cancel_invitation(original_action_hash: ActionHash) -> ExternResult<ActionHash> {
     let my_pub_key: AgentPubKey = agent_info()?.agent_latest_pubkey;
     let entry_info = get_invitation_info(&original_action_hash)?;
-    create_link(
-        entry_info.creation_hash.clone(),
-        my_pub_key,
-        LinkTypes::InviteToAgent,
-        LinkTag::new(String::from("rejected")),
-    )?;
-    let committed_link_hash = commit_invitation(entry_info.creation_hash)?;
-    return Ok(committed_link_hash)
+    if entry_info.author != my_pub_key {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from("Only the author of the invitation can cancel it"))));
+    }
+    if entry_info.cancelled {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from("This invitation has already been cancelled"))));
+    }
+    delete_entry(entry_info.creation_hash)
+}
+
+//only the author can close, responses are locked from then on and every invitee is signalled from post_commit
+#[hdk_extern]
+pub fn close_invitation(original_action_hash: ActionHash) -> ExternResult<InviteInfo> {
+    let my_pub_key: AgentPubKey = agent_info()?.agent_latest_pubkey;
+    let entry_info = get_invitation_info(&original_action_hash)?;
+    if entry_info.author != my_pub_key {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from("Only the author of the invitation can close it"))));
+    }
+    let closed_invite = Invite {
+        closed: true,
+        ..entry_info.invitation
+    };
+    update_invite_entry(entry_info.creation_hash, closed_invite)
 }
 
 #[hdk_extern]
@@ -122,7 +196,7 @@ pub fn clear_invitation(original_action_hash: ActionHash) -> ExternResult<()> {
             Ok(())
         })
         .collect::<ExternResult<Vec<()>>>()?;
-    return Ok(())
+    Ok(())
 }
 
 
@@ -143,9 +217,192 @@ fn get_invite_info_from_links(links: Vec<Link>) -> ExternResult<Option<Vec<Invit
     Ok(None)
 }
 
+//the response link records the latest Invite version so integrity can check the responder was invited in it
+fn respond_to_invitation(original_action_hash: ActionHash, response: &str, guests: u32, note: Option<String>, reason: Option<ResponseReason>, occurrence: Option<Timestamp>, acceptance_hash: Option<ActionHash>) -> ExternResult<ActionHash> {
+    let my_pub_key: AgentPubKey = agent_info()?.agent_latest_pubkey;
+    let entry_info = get_invitation_info(&original_action_hash)?;
+    if entry_info.cancelled {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from("This invitation has been cancelled"))));
+    }
+    if entry_info.invitation.closed {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from("This invitation is closed for responses"))));
+    }
+    //a response after the rsvp deadline is left to validation, which checks it against the time of the response
+    //agents that joined with the invite code refer to their redemption
+    let redemption_hash = match entry_info.invitation.invitees.contains(&my_pub_key) {
+        true => None,
+        false => Some(
+            get_invite_redeemers(&entry_info.creation_hash, &entry_info.invitation)?
+                .into_iter()
+                .find(|(redeemer, _)| redeemer == &my_pub_key)
+                .map(|(_, redemption_hash)| redemption_hash)
+                .ok_or(wasm_error!(WasmErrorInner::Guest(String::from("Only invitees can respond to this invitation"))))?
+        ),
+    };
+    let last_invite_record = get_latest_record(entry_info.creation_hash.clone())?;
+    let response_tag = InviteResponseTag {
+        response: response.into(),
+        invite_action_hash: last_invite_record.action_address().clone(),
+        guests,
+        note,
+        reason,
+        occurrence,
+        redemption_hash,
+        acceptance_hash,
+    };
+    create_link(
+        entry_info.creation_hash.clone(), //action hash
+        my_pub_key,
+        LinkTypes::InviteToAgent,
+        LinkTag::try_from(response_tag)?,
+    )?;
+    commit_invitation(entry_info.creation_hash)
+}
+
+//shared by every extern that makes a new version of an invitation
+pub fn update_invite_entry(hash_result: ActionHash, updated_invite: Invite) -> ExternResult<InviteInfo> {
+    if is_invite_cancelled(&hash_result)? {
+        return Err(wasm_error!(WasmErrorInner::Guest(
+            "Cannot update an invitation that has been cancelled".to_string()
+        )));
+    }
+    let author = get_invite_author(&hash_result)?;
+    let last_invite_record = get_latest_record(hash_result.clone())?;
+    let last_invite: Invite = last_invite_record.entry().to_app_option().map_err(|e| wasm_error!(e))?
+    .ok_or(wasm_error!(WasmErrorInner::Guest(String::from("Could not de-serialize the Invite Entry in the record"))))?;
+
+    if last_invite.closed {
+        return Err(wasm_error!(WasmErrorInner::Guest(
+            "Cannot update an invitation that has been closed".to_string()
+        )));
+    }
+    //an encrypted invitation that was read by a host comes back with its details decrypted, they are encrypted again
+    //for the invitees of the new version
+    let updated_invite = if updated_invite.encrypted_details.is_some() && (updated_invite.location.is_some() || updated_invite.details.is_some()) {
+        let invitees = get_invite_with_redeemers(&hash_result, &updated_invite)?.invitees;
+        encrypt_invite_details(&author, updated_invite, &invitees)?
+    } else {
+        updated_invite
+    };
+    let update_hash = update_entry(last_invite_record.action_address().clone(), &updated_invite)?;
+    let added_groups: Vec<ActionHash> = updated_invite.groups.iter()
+        .filter(|group_hash| !last_invite.groups.contains(group_hash))
+        .cloned()
+        .collect();
+    link_groups(&hash_result, &update_hash, &added_groups)?;
+
+    //reconcile the agent links so added invitees see the invitation and removed ones no longer do
+    let added_invitees: Vec<AgentPubKey> = updated_invite.invitees.iter()
+        .filter(|invitee| !last_invite.invitees.contains(invitee))
+        .cloned()
+        .collect();
+    let removed_invitees: Vec<AgentPubKey> = last_invite.invitees.iter()
+        .filter(|invitee| !updated_invite.invitees.contains(invitee))
+        .cloned()
+        .collect();
+    link_invitees(&hash_result, &added_invitees)?;
+    for agent in removed_invitees.iter() {
+        delete_invite_links(agent, &hash_result, None)?;
+    }
+
+    //hosts that became invitees or stopped being co-hosts lose their "inviter" link and the others get one
+    let last_inviters = get_inviters(&author, &last_invite);
+    let updated_inviters = get_inviters(&author, &updated_invite);
+    for agent in last_inviters.iter().filter(|agent| !updated_inviters.contains(agent)) {
+        delete_invite_links(agent, &hash_result, Some(LinkTag::new("inviter")))?;
+    }
+    let added_inviters: Vec<AgentPubKey> = updated_inviters.into_iter()
+        .filter(|agent| !last_inviters.contains(agent))
+        .collect();
+    link_inviters(&hash_result, &added_inviters)?;
+
+    //a new time resets the responses given to the old one, those invitees are pending again
+    if !has_same_time(&last_invite, &updated_invite) {
+        let history = get_invite_response_history(&hash_result)?;
+        let updated_invitees = get_invite_with_redeemers(&hash_result, &updated_invite)?.invitees;
+        let responded: Vec<AgentPubKey> = group_invitee_responses(&get_invite_with_redeemers(&hash_result, &last_invite)?, history, None)?.responses
+            .into_iter()
+            .map(|response| response.invitee)
+            .filter(|invitee| updated_invitees.contains(invitee))
+            .collect();
+        for agent in responded.iter() {
+            delete_invite_links(agent, &hash_result, Some(LinkTag::new("commited")))?;
+        }
+        link_invitees(&hash_result, &responded)?;
+    }
+    get_invitation_update_info(&update_hash)
+}
+
+//responses only hold for the time they were given to
+fn has_same_time(invite: &Invite, other: &Invite) -> bool {
+    invite.start_time == other.start_time && invite.end_time == other.end_time
+}
+
+fn link_inviters(create_action_hash: &ActionHash, inviters: &[AgentPubKey]) -> ExternResult<()> {
+    for agent in inviters.iter() {
+        create_link(
+            agent.clone(),
+            create_action_hash.clone(),
+            LinkTypes::AgentToInvite,
+            LinkTag::new(String::from("inviter")),
+        )?;
+    }
+    Ok(())
+}
+
+//the author and the co-hosts of an invitation
+pub fn get_invite_hosts(author: &AgentPubKey, invite: &Invite) -> Vec<AgentPubKey> {
+    let mut hosts: Vec<AgentPubKey> = vec![author.clone()];
+    for co_host in invite.co_hosts.iter() {
+        if !hosts.contains(co_host) {
+            hosts.push(co_host.clone());
+        }
+    }
+    hosts
+}
+
+//hosts that are not invitees keep track of the invitation with an "inviter" link
+fn get_inviters(author: &AgentPubKey, invite: &Invite) -> Vec<AgentPubKey> {
+    get_invite_hosts(author, invite).into_iter()
+        .filter(|host| !invite.invitees.contains(host))
+        .collect()
+}
+
+fn link_invitees(create_action_hash: &ActionHash, invitees: &[AgentPubKey]) -> ExternResult<()> {
+    for agent in invitees.iter() {
+        create_link(
+            agent.clone(),
+            create_action_hash.clone(),
+            LinkTypes::AgentToInvite,
+            LinkTag::new(String::from("pending")),
+        )?;
+    }
+    Ok(())
+}
+
+//removes the agent links to an invitation, optionally only those with the given tag
+fn delete_invite_links(agent: &AgentPubKey, create_action_hash: &ActionHash, tag: Option<LinkTag>) -> ExternResult<()> {
+    let links = get_links(agent.clone(), LinkTypes::AgentToInvite, tag)?;
+    for link in links.into_iter().filter(|link| link.target == HoloHash::from(create_action_hash.clone())) {
+        delete_link(link.create_link_hash)?;
+    }
+    Ok(())
+}
+
 //no option to update link tags, so we delete and create a new link
+//an invitee changing their response is already committed, so the existing link is kept
 fn commit_invitation(original_action_hash: ActionHash) -> ExternResult<ActionHash> {
     let my_pub_key: AgentPubKey = agent_info()?.agent_latest_pubkey;
+    let committed_links = get_links(
+        my_pub_key.clone(),
+        LinkTypes::AgentToInvite,
+        Some(LinkTag::new("commited")),
+    )?;
+    if let Some(committed_link) = committed_links
+        .into_iter()
+        .find(|link| link.target == HoloHash::from(original_action_hash.clone())) {
+        return Ok(committed_link.create_link_hash);
+    }
     let links = get_links(
         agent_info()?.agent_latest_pubkey, 
         LinkTypes::AgentToInvite,
@@ -167,7 +424,7 @@ fn commit_invitation(original_action_hash: ActionHash) -> ExternResult<ActionHas
         LinkTypes::AgentToInvite,
         LinkTag::new(String::from("commited")),
     )?;
-    return Ok(committed_link);
+    Ok(committed_link)
 }
 
 
@@ -176,7 +433,7 @@ fn commit_invitation(original_action_hash: ActionHash) -> ExternResult<ActionHas
    in practice we dont expect many invite updates.. A performance enhancement if there were many updates
    would be to link the creation_action to the last_update_action
  */ 
-fn get_latest_record(action_hash: ActionHash) -> ExternResult<Record> {
+pub fn get_latest_record(action_hash: ActionHash) -> ExternResult<Record> {
     let details = get_details(action_hash, GetOptions::default())?.ok_or(wasm_error!(
         WasmErrorInner::Guest("invite not found".into())
     ))?;
@@ -196,7 +453,7 @@ fn get_latest_record(action_hash: ActionHash) -> ExternResult<Record> {
 //if all the Action::Updates retained a copy of the genesis create action .. we could remove this function and just get that
 fn get_creation_action_hash(invite_record:&Record) -> ExternResult<ActionHash> {
     if let ActionType::Create = invite_record.action().action_type(){
-        return Ok(invite_record.action_address().clone())
+        Ok(invite_record.action_address().clone())
     }
     else {
         if let Action::Update(update) = invite_record.action(){
@@ -204,7 +461,7 @@ fn get_creation_action_hash(invite_record:&Record) -> ExternResult<ActionHash> {
                 wasm_error!(
                 WasmErrorInner::Guest(String::from("Could not find the Invitation record"))),
             )?;
-        return get_creation_action_hash(&previous_record);
+        get_creation_action_hash(&previous_record)
     } else {
         Err(wasm_error!(
             WasmErrorInner::Guest(String::from("something is seriously wrong "))
@@ -213,12 +470,42 @@ fn get_creation_action_hash(invite_record:&Record) -> ExternResult<ActionHash> {
     }
 }
 
+//the Invite of the latest record in the update chain
+pub fn get_latest_invite(create_action_hash: &ActionHash) -> ExternResult<Invite> {
+    get_latest_record(create_action_hash.clone())?.entry().to_app_option().map_err(|e| wasm_error!(e))?
+    .ok_or(wasm_error!(WasmErrorInner::Guest(String::from("Could not de-serialize the Invite Entry in the record"))))
+}
+
+//the time of the first version that closed the invitation, closed versions cannot be updated so it is the last one
+fn get_invite_closed_at(action_hash: ActionHash) -> ExternResult<Option<Timestamp>> {
+    let details = get_details(action_hash, GetOptions::default())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest("invite not found".into())
+    ))?;
+
+    match details {
+        Details::Entry(_) => Err(wasm_error!(WasmErrorInner::Guest(
+            "Malformed details".into()
+        ))),
+        Details::Record(element_details) => {
+            let invite: Invite = element_details.record.entry().to_app_option().map_err(|e| wasm_error!(e))?
+            .ok_or(wasm_error!(WasmErrorInner::Guest(String::from("Could not de-serialize the Invite Entry in the record"))))?;
+            if invite.closed {
+                return Ok(Some(element_details.record.action().timestamp()));
+            }
+            match element_details.updates.last() {
+                Some(update) => get_invite_closed_at(update.action_address().clone()),
+                None => Ok(None),
+            }
+        }
+    }
+}
+
 pub fn get_invitation_info(original_action_hash: &ActionHash) -> ExternResult<InviteInfo> {
     let invite_record = get_latest_record(original_action_hash.clone())?;
     let invitation_entry: Invite = invite_record.entry.clone().to_app_option().map_err(|e| wasm_error!(e))?
     .ok_or(wasm_error!(WasmErrorInner::Guest(String::from("Could not de-serialize the Invite Entry in the record"))))?;
     
-    return get_invitation_info_details(invitation_entry, invite_record, original_action_hash)
+    get_invitation_info_details(invitation_entry, invite_record, original_action_hash)
 }
 
 //unfortunately we loose reference to the creationHash in the post-commit callback and have to use a helper function to get it from the DHT/source chain
@@ -231,38 +518,254 @@ pub fn get_invitation_update_info(update_action_hash: &ActionHash) -> ExternResu
 
     //get creation hash from cycling update chain...
     let creation_action_hash = get_creation_action_hash(&invite_record)?;
-    return get_invitation_info_details(invite_entry, invite_record, &creation_action_hash)
+    get_invitation_info_details(invite_entry, invite_record, &creation_action_hash)
 }
 
-//DTO for all returns and signals
-pub fn get_invitation_info_details(invite:Invite, invite_record: Record, create_action_hash: &ActionHash)-> ExternResult<InviteInfo> {
-    let invitees_who_accepted: Vec<AgentPubKey> = get_links(
+//a cancelled invitation is one whose creation record has been deleted, its history stays readable on the DHT
+fn is_invite_cancelled(create_action_hash: &ActionHash) -> ExternResult<bool> {
+    match get_details(create_action_hash.clone(), GetOptions::default())? {
+        Some(Details::Record(record_details)) => Ok(!record_details.deletes.is_empty()),
+        _ => Err(wasm_error!(WasmErrorInner::Guest("invite not found".into()))),
+    }
+}
+
+//co-hosts can update the invitation, so the author is the one of the creation record
+fn get_invite_author(create_action_hash: &ActionHash) -> ExternResult<AgentPubKey> {
+    match get_details(create_action_hash.clone(), GetOptions::default())? {
+        Some(Details::Record(record_details)) => Ok(record_details.record.action().author().clone()),
+        _ => Err(wasm_error!(WasmErrorInner::Guest("invite not found".into()))),
+    }
+}
+
+//get a specific version of an Invite, eg. the one an update was made from
+pub fn get_invite_version(invite_action_hash: &ActionHash) -> ExternResult<Invite> {
+    let invite_record = get(invite_action_hash.clone(), GetOptions::default())?
+    .ok_or(wasm_error!(WasmErrorInner::Guest(String::from("Could not find the Invitation record"))))?;
+    invite_record.entry.to_app_option().map_err(|e| wasm_error!(e))?
+    .ok_or(wasm_error!(WasmErrorInner::Guest(String::from("Could not de-serialize the Invite Entry in the record"))))
+}
+
+//responses are never deleted, so the links to an invitation are its full response history.
+//responses given after the invitation was closed are left out, validation cannot tell them apart when they cite an older version
+pub fn get_invite_response_history(create_action_hash: &ActionHash) -> ExternResult<Vec<InviteResponse>> {
+    let closed_at = get_invite_closed_at(create_action_hash.clone())?;
+    let mut responses: Vec<InviteResponse> = get_links(
         create_action_hash.clone(),
         LinkTypes::InviteToAgent,
-        Some(LinkTag::new("accepted")),
+        None,
     )?.into_iter()
-    .map(|link| AgentPubKey::try_from(link.target).unwrap())
+    .filter_map(|link| {
+        let response_tag = read_response_tag(link.tag, create_action_hash)?;
+        Some(InviteResponse {
+            invitee: AgentPubKey::try_from(link.target).ok()?,
+            response: response_tag.response,
+            guests: response_tag.guests,
+            note: response_tag.note,
+            reason: response_tag.reason,
+            occurrence: response_tag.occurrence,
+            invite_action_hash: response_tag.invite_action_hash,
+            timestamp: link.timestamp,
+            response_hash: link.create_link_hash,
+            countersigned: response_tag.acceptance_hash.is_some(),
+        })
+    })
+    .filter(|response: &InviteResponse| match closed_at {
+        Some(closed_at) => response.timestamp <= closed_at,
+        None => true,
+    })
     .collect();
+    responses.sort_by(|a, b| a.timestamp.cmp(&b.timestamp).then(a.response_hash.cmp(&b.response_hash)));
+    Ok(responses)
+}
 
-    let invitees_who_rejected: Vec<AgentPubKey> = get_links(
-        create_action_hash.clone(),
-        LinkTypes::InviteToAgent,
-        Some(LinkTag::new("rejected")),
-    )?.into_iter()
-    .map(|link| AgentPubKey::try_from(link.target).unwrap())
+//responses given before the tag recorded the version were tagged with just "accepted" or "rejected",
+//they are read as a response to the invitation as it was created without guests, note or occurrence
+pub fn read_response_tag(tag: LinkTag, create_action_hash: &ActionHash) -> Option<InviteResponseTag> {
+    if let Ok(response_tag) = InviteResponseTag::try_from(tag.clone()) {
+        return Some(response_tag);
+    }
+    let response = String::from_utf8(tag.into_inner()).ok()?;
+    if response != "accepted" && response != "rejected" {
+        return None;
+    }
+    Some(InviteResponseTag {
+        response,
+        invite_action_hash: create_action_hash.clone(),
+        guests: 0,
+        note: None,
+        reason: None,
+        occurrence: None,
+        redemption_hash: None,
+        acceptance_hash: None,
+    })
+}
+
+//the most recent response of each invitee supersedes the earlier ones
+pub fn get_latest_responses(history: Vec<InviteResponse>) -> Vec<InviteResponse> {
+    let mut latest_responses: Vec<InviteResponse> = vec![];
+    for response in history.into_iter() {
+        latest_responses.retain(|latest| latest.invitee != response.invitee);
+        latest_responses.push(response);
+    }
+    latest_responses
+}
+
+//invitees of an invitation grouped by their latest response
+pub struct InviteeResponses {
+    pub accepted: Vec<AgentPubKey>,
+    pub rejected: Vec<AgentPubKey>,
+    pub tentative: Vec<AgentPubKey>,
+    pub waitlisted: Vec<AgentPubKey>,
+    pub pending: Vec<AgentPubKey>,
+    pub headcount: u32,
+    pub responses: Vec<InviteResponse>,
+}
+
+//acceptances are taken in the order they were given, once max_attendees is reached the rest are waitlisted.
+//when an attendee declines or is removed the first waitlisted invitee moves up.
+//responses to all occurrences of a recurring invitation count for each one of them, unless overridden for that occurrence
+pub fn group_invitee_responses(invite: &Invite, mut history: Vec<InviteResponse>, occurrence: Option<Timestamp>) -> ExternResult<InviteeResponses> {
+    history.retain(|response| response.occurrence.is_none() || response.occurrence == occurrence);
+
+    //an acceptance citing a version that did not countersign acceptances does not count once the invitation does
+    history.retain(|response| !invite.countersign_acceptances || response.response != "accepted" || response.countersigned);
+
+    let mut versions: BTreeMap<ActionHash, Invite> = BTreeMap::new();
+    for response in history.iter() {
+        if !versions.contains_key(&response.invite_action_hash) {
+            versions.insert(response.invite_action_hash.clone(), get_invite_version(&response.invite_action_hash)?);
+        }
+    }
+    let is_same_time = |response: &InviteResponse| versions.get(&response.invite_action_hash)
+        .is_some_and(|version| has_same_time(version, invite));
+
+    //accepting again, eg. to change the number of guests, keeps the place of the first accept in an unbroken run of accepts
+    let mut accepted_since: BTreeMap<AgentPubKey, Timestamp> = BTreeMap::new();
+    for response in history.iter() {
+        if response.response == "accepted" && is_same_time(response) {
+            accepted_since.entry(response.invitee.clone()).or_insert(response.timestamp);
+        } else {
+            accepted_since.remove(&response.invitee);
+        }
+    }
+
+    //responses of agents that were removed from the invitation are ignored
+    let mut responses = get_latest_responses(history.clone());
+    responses.retain(|response| invite.invitees.contains(&response.invitee));
+
+    //as are responses given to a version of the invitation with another time
+    responses.retain(is_same_time);
+
+    let mut acceptances: Vec<&InviteResponse> = responses.iter()
+        .filter(|response| response.response == "accepted")
+        .collect();
+    acceptances.sort_by_key(|response| accepted_since.get(&response.invitee).cloned().unwrap_or(response.timestamp));
+
+    //max_attendees counts the guests too, an acceptance whose party does not fit in the places left is waitlisted
+    //while a smaller party after it can still take them
+    let mut accepted: Vec<AgentPubKey> = vec![];
+    let mut waitlisted: Vec<AgentPubKey> = vec![];
+    let mut headcount: u32 = 0;
+    for response in acceptances {
+        let has_place = match invite.max_attendees {
+            Some(max_attendees) => headcount + 1 + response.guests <= max_attendees,
+            None => true,
+        };
+        if has_place {
+            accepted.push(response.invitee.clone());
+            headcount += 1 + response.guests;
+        } else {
+            waitlisted.push(response.invitee.clone());
+        }
+    }
+
+    let rejected: Vec<AgentPubKey> = responses.iter()
+    .filter(|response| response.response == "rejected")
+    .map(|response| response.invitee.clone())
+    .collect();
+
+    let tentative: Vec<AgentPubKey> = responses.iter()
+    .filter(|response| response.response == "tentative")
+    .map(|response| response.invitee.clone())
     .collect();
 
-    let mut invitees_pending: Vec<AgentPubKey> = invite.invitees.clone();
-    invitees_pending.retain(|x| !invitees_who_accepted.contains(&x) && !invitees_who_rejected.contains(&x));
+    let mut pending: Vec<AgentPubKey> = invite.invitees.clone();
+    pending.retain(|x| !responses.iter().any(|response| &response.invitee == x));
+
+    Ok(InviteeResponses { accepted, rejected, tentative, waitlisted, pending, headcount, responses })
+}
+
+//invitees that were waitlisted before and have a place now
+pub fn get_promoted_invitees(before: &InviteeResponses, after: &InviteeResponses) -> Vec<AgentPubKey> {
+    before.waitlisted.iter()
+    .filter(|invitee| after.accepted.contains(invitee))
+    .cloned()
+    .collect()
+}
+
+//invitations without a minimum of attendees always have their quorum
+pub fn is_quorum_reached(invite: &Invite, invitee_responses: &InviteeResponses) -> bool {
+    match invite.min_attendees {
+        Some(min_attendees) => invitee_responses.accepted.len() >= min_attendees as usize,
+        None => true,
+    }
+}
+
+//DTO for all returns and signals
+pub fn get_invitation_info_details(invite:Invite, invite_record: Record, create_action_hash: &ActionHash)-> ExternResult<InviteInfo> {
+    let invite = decrypt_invite_details(invite)?;
+    let invite_with_redeemers = get_invite_with_redeemers(create_action_hash, &invite)?;
+    let invitees_redeemed: Vec<AgentPubKey> = invite_with_redeemers.invitees.iter()
+        .filter(|invitee| !invite.invitees.contains(invitee))
+        .cloned()
+        .collect();
+    let invitee_responses = group_invitee_responses(&invite_with_redeemers, get_invite_response_history(create_action_hash)?, None)?;
+    let author = get_invite_author(create_action_hash)?;
+    let cancelled = is_invite_cancelled(create_action_hash)?;
+    let invitees_checked_in = get_checked_in_invitees(create_action_hash, &author, &invite)?;
+    let now = sys_time()?;
+    let expired = invite.rsvp_deadline.is_some_and(|rsvp_deadline| rsvp_deadline < now);
+    //once nobody can respond anymore an invitation without its quorum did not happen
+    let quorum_reached = is_quorum_reached(&invite, &invitee_responses);
+    let did_not_happen = (expired || invite.closed) && !quorum_reached;
+    let invitee_roles: Vec<InviteeRole> = invite_with_redeemers.invitees.iter()
+        .map(|invitee| get_invitee_role(&invite, invitee))
+        .collect();
+    let is_required = |invitee: &AgentPubKey| get_invitee_role(&invite, invitee).required;
+    let (required_invitees_who_accepted, optional_invitees_who_accepted): (Vec<AgentPubKey>, Vec<AgentPubKey>) = invitee_responses.accepted
+        .iter()
+        .cloned()
+        .partition(is_required);
+    let (required_invitees_who_rejected, optional_invitees_who_rejected): (Vec<AgentPubKey>, Vec<AgentPubKey>) = invitee_responses.rejected
+        .iter()
+        .cloned()
+        .partition(is_required);
    
-    return Ok(InviteInfo {
+    Ok(InviteInfo {
         invitation: invite.clone(),
         creation_hash: create_action_hash.clone(),
-        author: invite_record.action().author().clone(),
+        author,
         timestamp: invite_record.action().timestamp(),
-        invitees_who_accepted,
-        invitees_who_rejected,
-        invitees_pending
+        invitees_who_accepted: invitee_responses.accepted,
+        invitees_who_rejected: invitee_responses.rejected,
+        invitees_who_are_tentative: invitee_responses.tentative,
+        invitees_waitlisted: invitee_responses.waitlisted,
+        invitees_pending: invitee_responses.pending,
+        invitees_redeemed,
+        invitees_checked_in,
+        invitees_delivered: get_delivered_invitees(create_action_hash)?,
+        invitee_roles,
+        slot_tallies: get_slot_tallies(create_action_hash, &invite)?,
+        required_invitees_who_accepted,
+        optional_invitees_who_accepted,
+        required_invitees_who_rejected,
+        optional_invitees_who_rejected,
+        headcount: invitee_responses.headcount,
+        invitee_responses: invitee_responses.responses,
+        cancelled,
+        expired,
+        quorum_reached,
+        did_not_happen
     })
 }
 
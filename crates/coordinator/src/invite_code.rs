@@ -0,0 +1,92 @@
+use hdk::prelude::*;
+use hc_integrity_zome_invitations::*;
+use crate::invite::{get_invitation_info, get_latest_record};
+
+//the signature is made in the UI with the secret key of the invite code, over the 39 bytes of our own agent key
+#[hdk_extern]
+pub fn redeem_invite_code(input: RedeemInput) -> ExternResult<InviteInfo> {
+    let my_pub_key: AgentPubKey = agent_info()?.agent_latest_pubkey;
+    let entry_info = get_invitation_info(&input.creation_hash)?;
+    if entry_info.cancelled {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from("This invitation has been cancelled"))));
+    }
+    let invite_code = entry_info.invitation.invite_code.clone().ok_or(wasm_error!(
+        WasmErrorInner::Guest(String::from("This invitation has no invite code"))
+    ))?;
+    if entry_info.invitation.invitees.contains(&my_pub_key) || entry_info.invitees_redeemed.contains(&my_pub_key) {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from("You are already invited"))));
+    }
+    let now = sys_time()?;
+    if invite_code.expires_at.is_some_and(|expires_at| expires_at < now) {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from("The invite code has expired"))));
+    }
+    if invite_code.max_redemptions.is_some_and(|max_redemptions| entry_info.invitees_redeemed.len() >= max_redemptions as usize) {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from("The invite code has been redeemed too many times"))));
+    }
+    let last_invite_record = get_latest_record(input.creation_hash.clone())?;
+    let redemption_tag = RedemptionTag {
+        invite_action_hash: last_invite_record.action_address().clone(),
+        signature: input.signature,
+    };
+    create_link(
+        input.creation_hash.clone(),
+        my_pub_key.clone(),
+        LinkTypes::InviteToRedeemer,
+        LinkTag::try_from(redemption_tag)?,
+    )?;
+    create_link(
+        my_pub_key,
+        input.creation_hash.clone(),
+        LinkTypes::AgentToInvite,
+        LinkTag::new(String::from("pending")),
+    )?;
+    get_invitation_info(&input.creation_hash)
+}
+
+
+
+
+//************ Helpers **************************
+
+//agents that joined with the current invite code and the hash of their redemption, in the order they redeemed it.
+//validation checked a redemption against the version it cites, so redemptions of a removed or rotated code
+//or made after the current expiry are ignored, as are redemptions beyond max_redemptions
+pub fn get_invite_redeemers(create_action_hash: &ActionHash, invite: &Invite) -> ExternResult<Vec<(AgentPubKey, ActionHash)>> {
+    let invite_code = match &invite.invite_code {
+        Some(invite_code) => invite_code,
+        None => return Ok(vec![]),
+    };
+    let mut links = get_links(create_action_hash.clone(), LinkTypes::InviteToRedeemer, None)?;
+    links.sort_by(|a, b| a.timestamp.cmp(&b.timestamp).then(a.create_link_hash.cmp(&b.create_link_hash)));
+    let mut redeemers: Vec<(AgentPubKey, ActionHash)> = vec![];
+    for link in links.into_iter() {
+        let redeemer = AgentPubKey::try_from(link.target).map_err(|err| wasm_error!(err))?;
+        if redeemers.iter().any(|(agent, _)| agent == &redeemer) {
+            continue;
+        }
+        if invite_code.expires_at.is_some_and(|expires_at| link.timestamp > expires_at) {
+            continue;
+        }
+        let redemption_tag = match RedemptionTag::try_from(link.tag) {
+            Ok(redemption_tag) => redemption_tag,
+            Err(_) => continue,
+        };
+        if verify_signature_raw(invite_code.public_key.clone(), redemption_tag.signature, redeemer.get_raw_39().to_vec())? {
+            redeemers.push((redeemer, link.create_link_hash));
+        }
+    }
+    redeemers.retain(|(agent, _)| !invite.invitees.contains(agent));
+    if let Some(max_redemptions) = invite_code.max_redemptions {
+        redeemers.truncate(max_redemptions as usize);
+    }
+    Ok(redeemers)
+}
+
+//the invitation as it is seen when grouping responses, with the redeemers among its invitees
+pub fn get_invite_with_redeemers(create_action_hash: &ActionHash, invite: &Invite) -> ExternResult<Invite> {
+    let mut invite = invite.clone();
+    for (redeemer, _) in get_invite_redeemers(create_action_hash, &invite)?.into_iter() {
+        invite.invitees.push(redeemer);
+    }
+    Ok(invite)
+}
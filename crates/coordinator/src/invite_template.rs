@@ -0,0 +1,96 @@
+use std::collections::BTreeMap;
+use hdk::prelude::*;
+use hc_integrity_zome_invitations::*;
+use crate::invite::create_invitation;
+use crate::utils::query_my_latest_records;
+
+//templates are private entries, they only live on the source chain of their author
+#[hdk_extern]
+pub fn create_invite_template(template: InviteTemplate) -> ExternResult<InviteTemplateInfo> {
+    let template_hash = create_entry(&EntryTypes::InviteTemplate(template))?;
+    get_my_template(&template_hash)
+}
+
+
+#[hdk_extern]
+pub fn get_my_invite_templates(_: ()) -> ExternResult<Vec<InviteTemplateInfo>> {
+    Ok(get_my_latest_templates()?.into_values().collect())
+}
+
+
+#[hdk_extern]
+pub fn update_invite_template(input: UpdateInviteTemplateInput) -> ExternResult<InviteTemplateInfo> {
+    let latest_record = get_my_latest_template_record(&input.template_hash)?;
+    update_entry(latest_record.action_address().clone(), &EntryTypes::InviteTemplate(input.template))?;
+    get_my_template(&input.template_hash)
+}
+
+
+#[hdk_extern]
+pub fn delete_invite_template(template_hash: ActionHash) -> ExternResult<ActionHash> {
+    let latest_record = get_my_latest_template_record(&template_hash)?;
+    delete_entry(latest_record.action_address().clone())
+}
+
+
+//the invitation ends after the duration of the template, everything else that is not in the template is left to its default
+#[hdk_extern]
+pub fn create_invitation_from_template(input: InviteFromTemplateInput) -> ExternResult<InviteInfo> {
+    let template = get_my_template(&input.template_hash)?.template;
+    let invitation = InviteInput {
+        invitees: template.invitees,
+        invitee_roles: vec![],
+        co_hosts: vec![],
+        location: template.location,
+        start_time: Some(input.start_time),
+        end_time: template.duration.map(|duration| Timestamp::from_micros(input.start_time.as_micros() + duration)),
+        details: template.details,
+        rsvp_deadline: None,
+        max_attendees: None,
+        min_attendees: None,
+        allow_guests: false,
+        max_guests_per_invitee: None,
+        recurrence: None,
+        invite_code: None,
+        groups: vec![],
+        slots: vec![],
+        encrypt_details: None,
+        countersign_acceptances: false,
+        require_check_in_token: false,
+        creation_hash: None,
+    };
+    create_invitation(invitation)
+}
+
+
+
+
+//************ Helpers **************************
+
+fn get_my_template(template_hash: &ActionHash) -> ExternResult<InviteTemplateInfo> {
+    get_my_latest_templates()?.remove(template_hash).ok_or(wasm_error!(
+        WasmErrorInner::Guest(String::from("Could not find the template"))
+    ))
+}
+
+fn get_my_latest_template_record(template_hash: &ActionHash) -> ExternResult<Record> {
+    query_my_latest_records(UnitEntryTypes::InviteTemplate.try_into()?)?.remove(template_hash).ok_or(wasm_error!(
+        WasmErrorInner::Guest(String::from("Could not find the template"))
+    ))
+}
+
+//latest version of every template that was not deleted, by the hash of its creation
+fn get_my_latest_templates() -> ExternResult<BTreeMap<ActionHash, InviteTemplateInfo>> {
+    let records = query_my_latest_records(UnitEntryTypes::InviteTemplate.try_into()?)?;
+    let mut templates: BTreeMap<ActionHash, InviteTemplateInfo> = BTreeMap::new();
+    for (template_hash, record) in records.into_iter() {
+        let template: InviteTemplate = record.entry().to_app_option().map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(String::from("Could not de-serialize the InviteTemplate Entry in the record"))))?;
+        templates.insert(template_hash.clone(), InviteTemplateInfo {
+            template,
+            template_hash,
+            timestamp: record.action().timestamp(),
+        });
+    }
+    Ok(templates)
+}
@@ -1,5 +1,18 @@
 pub mod signals;
 pub mod invite;
+pub mod time_proposal;
+pub mod recurrence;
+pub mod invite_template;
+pub mod invite_code;
+pub mod group;
+pub mod slot_poll;
+pub mod encryption;
+pub mod acceptance;
+pub mod check_in;
+pub mod reminder;
+pub mod notification;
+pub mod delivery;
+pub mod utils;
 
 use hdk::prelude::{*, holo_hash::hash_type};
 use hc_integrity_zome_invitations::*;
@@ -9,6 +22,11 @@ use signals::Signal;
 pub fn init(_: ()) -> ExternResult<InitCallbackResult> {
     let mut functions = BTreeSet::new();
     functions.insert((zome_info()?.name, "recv_remote_signal".into()));
+    //invitees run the countersigning session of their acceptance with the author
+    functions.insert((zome_info()?.name, "accept_acceptance_preflight".into()));
+    functions.insert((zome_info()?.name, "commit_countersigned_acceptance".into()));
+    //hosts deliver invitations with an acknowledgement
+    functions.insert((zome_info()?.name, "receive_invitation_notice".into()));
 
     let grant = ZomeCallCapGrant {
         access: CapAccess::Unrestricted, // Unrestricted access means any external agent can call the extern
@@ -16,12 +34,15 @@ pub fn init(_: ()) -> ExternResult<InitCallbackResult> {
         tag: "recv_remote_signal_cap_grant".into()
     };
     create_cap_grant(grant)?;
+    encryption::publish_encryption_key(())?;
+    schedule("send_reminders")?;
+    schedule("retry_invitation_notices")?;
     Ok(InitCallbackResult::Pass)
 }
 
 #[hdk_extern] 
 fn recv_remote_signal(signal: Signal) -> ExternResult<()> {
-    emit_signal(signal)?;
+    signals::emit_to_ui(signal, false)?;
     Ok(())
 }
 
@@ -33,40 +54,121 @@ pub fn post_commit(committed_actions: Vec<SignedActionHashed>) {
         }
     }
 }
+//every action is signalled to the other agents and emitted once to our own UI,
+//follow-up signals such as quorum_reached and waitlist_promoted only go to the other agents
 fn signal_action(action: SignedActionHashed) -> ExternResult<()> {
     match action.hashed.content.clone() {
-        Action::Create(_create) => {
+        Action::Create(create) => {
+            if !is_invite_entry(&create.entry_type) {
+                return Ok(());
+            }
             if let Ok(Some(invite_entry_info)) = get_invitation_detail(&action.hashed.hash) {
-                signals::invitation_received(action, invite_entry_info.clone())?;
+                let signal = signals::invitation_received(action, invite_entry_info.clone())?;
+                signals::emit_to_ui(signal, true)?;
             }
             Ok(())
         }
-        Action::Update(_update) => {
+        Action::Update(update) => {
+            if !is_invite_entry(&update.entry_type) {
+                return Ok(());
+            }
             if let Ok(Some(invite_entry_info)) = get_invitation_detail_update(&action.hashed.hash) {
-                signals::invitation_updated(action, invite_entry_info.clone())?;
+                let previous_invite = invite::get_invite_version(&update.original_action_address)?;
+                let history = invite::get_invite_response_history(&invite_entry_info.creation_hash)?;
+                let previous_responses = invite::group_invitee_responses(
+                    &invite_code::get_invite_with_redeemers(&invite_entry_info.creation_hash, &previous_invite)?,
+                    history.clone(),
+                    None,
+                )?;
+                let invitee_responses = invite::group_invitee_responses(
+                    &invite_code::get_invite_with_redeemers(&invite_entry_info.creation_hash, &invite_entry_info.invitation)?,
+                    history,
+                    None,
+                )?;
+                let promoted_invitees = invite::get_promoted_invitees(&previous_responses, &invitee_responses);
+                signals::waitlist_promoted(action.clone(), invite_entry_info.clone(), promoted_invitees)?;
+                if !invite::is_quorum_reached(&previous_invite, &previous_responses) && invite_entry_info.quorum_reached {
+                    signals::quorum_reached(action.clone(), invite_entry_info.clone())?;
+                }
+                let signal = if invite_entry_info.invitation.closed && !previous_invite.closed {
+                    signals::invitation_closed(action, invite_entry_info)?
+                } else if is_slot_poll_open(&previous_invite) && invite_entry_info.invitation.start_time.is_some() {
+                    signals::slot_finalized(action, invite_entry_info)?
+                } else {
+                    signals::invitation_updated(action, invite_entry_info.clone(), previous_invite.invitees)?
+                };
+                signals::emit_to_ui(signal, true)?;
             }
             Ok(())
         }
-        Action::Delete(_delete) => {
-            /*if let Ok(Some(original_app_entry))
-                = get_entry_for_action(&delete.deletes_address) {
-                emit_signal(Signal::EntryDeleted {
-                    action,
-                    original_app_entry,
-                })?;
-            }*/
+        Action::Delete(delete) => {
+            if let Ok(Some(invite_entry_info)) = get_invitation_detail(&delete.deletes_address) {
+                let signal = signals::invitation_cancelled(action, invite_entry_info)?;
+                signals::emit_to_ui(signal, true)?;
+            }
             Ok(())
         }
         Action::CreateLink(create_link) => {
             if let Ok(Some(link_type)) = LinkTypes::from_type(create_link.zome_index, create_link.link_type) {
                 if link_type == LinkTypes::InviteToAgent {
                     let invite_entry_info = get_invitation_detail_by_link_target(create_link.base_address)?;
-                    if create_link.tag == LinkTag::new("accepted") {
-                        signals::invitation_accepted(action, invite_entry_info)?;
+                    let response_tag = InviteResponseTag::try_from(create_link.tag)?;
+                    let history = invite::get_invite_response_history(&invite_entry_info.creation_hash)?;
+                    let (previous_history, new_responses): (Vec<InviteResponse>, Vec<InviteResponse>) = history
+                        .clone()
+                        .into_iter()
+                        .partition(|response| response.response_hash != action.hashed.hash);
+                    let response = new_responses.into_iter().next().ok_or(wasm_error!("Response not found in the invitation history"))?;
+                    let invite = invite_code::get_invite_with_redeemers(&invite_entry_info.creation_hash, &invite_entry_info.invitation)?;
+                    //a response given before the time of the invitation changed does not count
+                    let responded_before = invite::group_invitee_responses(&invite, previous_history.clone(), response.occurrence)?
+                        .responses
+                        .iter()
+                        .any(|response| &response.invitee == action.hashed.author());
+                    let previous_responses = invite::group_invitee_responses(&invite, previous_history, None)?;
+                    let promoted_invitees = invite::get_promoted_invitees(
+                        &previous_responses,
+                        &invite::group_invitee_responses(&invite, history, None)?,
+                    );
+                    signals::waitlist_promoted(action.clone(), invite_entry_info.clone(), promoted_invitees)?;
+                    if !invite::is_quorum_reached(&invite, &previous_responses) && invite_entry_info.quorum_reached {
+                        signals::quorum_reached(action.clone(), invite_entry_info.clone())?;
+                    }
+                    let signal = if responded_before {
+                        signals::response_changed(action, invite_entry_info, response)?
                     }
-                    else if create_link.tag == LinkTag::new("rejected") {
-                        signals::invitation_rejected(action, invite_entry_info)?;
-                    } 
+                    else if response_tag.response == "accepted" {
+                        signals::invitation_accepted(action, invite_entry_info, response)?
+                    }
+                    else if response_tag.response == "rejected" {
+                        signals::invitation_rejected(action, invite_entry_info, response)?
+                    }
+                    else if response_tag.response == "tentative" {
+                        signals::invitation_tentative(action, invite_entry_info, response)?
+                    }
+                    else {
+                        return Ok(());
+                    };
+                    signals::emit_to_ui(signal, true)?;
+                }
+                else if link_type == LinkTypes::InviteToProposal {
+                    let invite_entry_info = get_invitation_detail_by_link_target(create_link.base_address)?;
+                    let proposal_hash = ActionHash::try_from(create_link.target_address).map_err(|err| wasm_error!(err))?;
+                    let proposal_info = time_proposal::get_time_proposal_info(&proposal_hash)?;
+                    let signal = signals::time_proposed(action, invite_entry_info, proposal_info)?;
+                    signals::emit_to_ui(signal, true)?;
+                }
+                else if link_type == LinkTypes::InviteToException {
+                    let invite_entry_info = get_invitation_detail_by_link_target(create_link.base_address)?;
+                    let exception_hash = ActionHash::try_from(create_link.target_address).map_err(|err| wasm_error!(err))?;
+                    let exception = recurrence::get_occurrence_exception(&exception_hash)?;
+                    let signal = signals::occurrence_changed(action, invite_entry_info, exception)?;
+                    signals::emit_to_ui(signal, true)?;
+                }
+                else if link_type == LinkTypes::InviteToCheckIn {
+                    let invite_entry_info = get_invitation_detail_by_link_target(create_link.base_address)?;
+                    let signal = signals::invitation_checked_in(action, invite_entry_info)?;
+                    signals::emit_to_ui(signal, true)?;
                 }
             }
             Ok(())
@@ -79,14 +181,28 @@ fn signal_action(action: SignedActionHashed) -> ExternResult<()> {
 }
 
 
+//other entry types of the zome are not signalled on create or update
+fn is_invite_entry(entry_type: &EntryType) -> bool {
+    match entry_type {
+        EntryType::App(app_entry_def) => matches!(
+            UnitEntryTypes::try_from(ScopedEntryDefIndex {
+                zome_index: app_entry_def.zome_index,
+                zome_type: app_entry_def.entry_index,
+            }),
+            Ok(UnitEntryTypes::Invite)
+        ),
+        _ => false,
+    }
+}
+
 fn get_invitation_detail(creation_action_hash: &ActionHash) -> ExternResult<Option<InviteInfo>> {
-    let invite_entry_info = invite::get_invitation_info(&creation_action_hash)?;
-    return Ok(Some(invite_entry_info));
+    let invite_entry_info = invite::get_invitation_info(creation_action_hash)?;
+    Ok(Some(invite_entry_info))
 }
 
 fn get_invitation_detail_update(update_action_hash: &ActionHash) -> ExternResult<Option<InviteInfo>> {
     let invite_entry_info = invite::get_invitation_update_info(update_action_hash)?;
-    return Ok(Some(invite_entry_info));
+    Ok(Some(invite_entry_info))
 }
 
 fn get_invitation_detail_by_link_target(link_target:HoloHash<hash_type::AnyLinkable>) -> ExternResult<InviteInfo> {
@@ -94,6 +210,6 @@ fn get_invitation_detail_by_link_target(link_target:HoloHash<hash_type::AnyLinka
         let invite_entry_info = invite::get_invitation_info(&action_hash)?;
         return Ok(invite_entry_info)
     }
-    return Err(wasm_error!("Invite_entry_info not found"))
+    Err(wasm_error!("Invite_entry_info not found"))
 
 }
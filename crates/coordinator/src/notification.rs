@@ -0,0 +1,81 @@
+use hdk::prelude::*;
+use hc_integrity_zome_invitations::*;
+
+//every notification left for us, oldest first, so a UI that reconnects can catch up on the signals it missed
+#[hdk_extern]
+pub fn get_notifications(_: ()) -> ExternResult<Vec<Notification>> {
+    let my_pub_key: AgentPubKey = agent_info()?.agent_latest_pubkey;
+    let mut links = get_links(my_pub_key, LinkTypes::AgentToNotification, None)?;
+    links.sort_by(|a, b| a.timestamp.cmp(&b.timestamp).then(a.create_link_hash.cmp(&b.create_link_hash)));
+    let read_notifications = get_read_notifications()?;
+    let mut notifications: Vec<Notification> = vec![];
+    for link in links.into_iter() {
+        let notification_tag = NotificationTag::try_from(link.tag)?;
+        notifications.push(Notification {
+            read: read_notifications.contains(&link.create_link_hash),
+            notification_hash: link.create_link_hash,
+            kind: notification_tag.kind,
+            creation_hash: notification_tag.creation_hash,
+            action_hash: ActionHash::try_from(link.target).map_err(|err| wasm_error!(err))?,
+            sender: link.author,
+            timestamp: link.timestamp,
+        });
+    }
+    Ok(notifications)
+}
+
+
+#[hdk_extern]
+pub fn mark_notification_read(notification_hash: ActionHash) -> ExternResult<()> {
+    if get_read_notifications()?.contains(&notification_hash) {
+        return Ok(());
+    }
+    if !get_notifications(())?.iter().any(|notification| notification.notification_hash == notification_hash) {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from("Could not find the notification"))));
+    }
+    create_entry(&EntryTypes::NotificationRead(NotificationRead { notification_hash }))?;
+    Ok(())
+}
+
+
+#[hdk_extern]
+pub fn get_unread_notification_count(_: ()) -> ExternResult<u32> {
+    Ok(get_notifications(())?.iter().filter(|notification| !notification.read).count() as u32)
+}
+
+
+
+
+//************ Helpers **************************
+
+fn get_read_notifications() -> ExternResult<Vec<ActionHash>> {
+    let records = query(
+        ChainQueryFilter::new()
+            .entry_type(UnitEntryTypes::NotificationRead.try_into()?)
+            .include_entries(true),
+    )?;
+    let mut read_notifications: Vec<ActionHash> = vec![];
+    for record in records.into_iter() {
+        if let Some(notification_read) = record.entry().to_app_option::<NotificationRead>().map_err(|e| wasm_error!(e))? {
+            read_notifications.push(notification_read.notification_hash);
+        }
+    }
+    Ok(read_notifications)
+}
+
+//leaves the notification in the inbox of every recipient, even when they are offline
+pub fn record_notifications(kind: &str, action_hash: &ActionHash, creation_hash: &ActionHash, recipients: &[AgentPubKey]) -> ExternResult<()> {
+    for recipient in recipients.iter() {
+        let notification_tag = NotificationTag {
+            kind: kind.into(),
+            creation_hash: creation_hash.clone(),
+        };
+        create_link(
+            recipient.clone(),
+            action_hash.clone(),
+            LinkTypes::AgentToNotification,
+            LinkTag::try_from(notification_tag)?,
+        )?;
+    }
+    Ok(())
+}
@@ -0,0 +1,118 @@
+use std::collections::BTreeMap;
+use hdk::prelude::*;
+use hc_integrity_zome_invitations::*;
+use crate::invite_code::get_invite_with_redeemers;
+use crate::invite::{get_invitation_info, get_invite_response_history, get_latest_record, group_invitee_responses};
+use crate::check_in::get_occurrence_checked_in_invitees;
+
+//occurrences of a recurring invitation starting in the window, with the exceptions applied
+//and the responses to all occurrences or to that occurrence grouped per occurrence
+#[hdk_extern]
+pub fn get_occurrences(input: OccurrencesInput) -> ExternResult<Vec<OccurrenceInfo>> {
+    let entry_info = get_invitation_info(&input.creation_hash)?;
+    let author = entry_info.author;
+    let invite = entry_info.invitation;
+    if invite.recurrence.is_none() {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from("This invitation does not recur"))));
+    }
+    let exceptions = get_latest_exceptions(&input.creation_hash, &invite)?;
+    let history = get_invite_response_history(&input.creation_hash)?;
+    let invite_with_redeemers = get_invite_with_redeemers(&input.creation_hash, &invite)?;
+    let duration = match (invite.start_time, invite.end_time) {
+        (Some(start_time), Some(end_time)) => Some(end_time.as_micros() - start_time.as_micros()),
+        _ => None,
+    };
+    let in_window = |start: Timestamp| start >= input.window_start && start <= input.window_end;
+
+    //occurrences moved into the window from outside of it are added as well
+    let mut original_starts = expand_occurrences(&invite, input.window_start, input.window_end);
+    for (original_start, exception) in exceptions.iter() {
+        if !original_starts.contains(original_start) && exception.start_time.is_some_and(in_window) {
+            original_starts.push(*original_start);
+        }
+    }
+
+    let mut occurrences: Vec<OccurrenceInfo> = vec![];
+    for original_start in original_starts.into_iter() {
+        let exception = exceptions.get(&original_start);
+        //a moved occurrence keeps the duration of the series unless it was given an end time
+        let start_time = exception.and_then(|exception| exception.start_time).unwrap_or(original_start);
+        let end_time = match exception.and_then(|exception| exception.end_time) {
+            Some(end_time) => Some(end_time),
+            None => duration.map(|duration| Timestamp::from_micros(start_time.as_micros() + duration)),
+        };
+        if !in_window(start_time) {
+            continue;
+        }
+        let invitee_responses = group_invitee_responses(&invite_with_redeemers, history.clone(), Some(original_start))?;
+        occurrences.push(OccurrenceInfo {
+            original_start,
+            start_time,
+            end_time,
+            cancelled: exception.is_some_and(|exception| exception.cancelled),
+            moved: start_time != original_start,
+            invitees_who_accepted: invitee_responses.accepted,
+            invitees_who_rejected: invitee_responses.rejected,
+            invitees_who_are_tentative: invitee_responses.tentative,
+            invitees_waitlisted: invitee_responses.waitlisted,
+            invitees_pending: invitee_responses.pending,
+            invitees_checked_in: get_occurrence_checked_in_invitees(&input.creation_hash, &author, &invite, original_start)?,
+        });
+    }
+    occurrences.sort_by_key(|occurrence| occurrence.start_time);
+    Ok(occurrences)
+}
+
+
+//cancels or moves a single occurrence without changing the series, a later exception for the same occurrence replaces an earlier one
+#[hdk_extern]
+pub fn add_occurrence_exception(input: OccurrenceExceptionInput) -> ExternResult<ActionHash> {
+    let entry_info = get_invitation_info(&input.creation_hash)?;
+    if entry_info.cancelled {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from("This invitation has been cancelled"))));
+    }
+    let last_invite_record = get_latest_record(input.creation_hash.clone())?;
+    let exception = OccurrenceException {
+        creation_hash: input.creation_hash.clone(),
+        invite_action_hash: last_invite_record.action_address().clone(),
+        original_start: input.original_start,
+        cancelled: input.cancelled,
+        start_time: input.start_time,
+        end_time: input.end_time,
+    };
+    let exception_hash = create_entry(&EntryTypes::OccurrenceException(exception))?;
+    create_link(
+        input.creation_hash,
+        exception_hash.clone(),
+        LinkTypes::InviteToException,
+        (),
+    )?;
+    Ok(exception_hash)
+}
+
+
+
+
+//************ Helpers **************************
+
+pub fn get_occurrence_exception(exception_hash: &ActionHash) -> ExternResult<OccurrenceException> {
+    let record = get(exception_hash.clone(), GetOptions::default())?
+    .ok_or(wasm_error!(WasmErrorInner::Guest(String::from("Could not find the occurrence exception record"))))?;
+    record.entry().to_app_option().map_err(|e| wasm_error!(e))?
+    .ok_or(wasm_error!(WasmErrorInner::Guest(String::from("Could not de-serialize the OccurrenceException Entry in the record"))))
+}
+
+//the latest exception of each occurrence that is still part of the series
+fn get_latest_exceptions(create_action_hash: &ActionHash, invite: &Invite) -> ExternResult<BTreeMap<Timestamp, OccurrenceException>> {
+    let mut links = get_links(create_action_hash.clone(), LinkTypes::InviteToException, None)?;
+    links.sort_by(|a, b| a.timestamp.cmp(&b.timestamp).then(a.create_link_hash.cmp(&b.create_link_hash)));
+    let mut exceptions: BTreeMap<Timestamp, OccurrenceException> = BTreeMap::new();
+    for link in links.into_iter() {
+        let exception_hash = ActionHash::try_from(link.target).map_err(|err| wasm_error!(err))?;
+        let exception = get_occurrence_exception(&exception_hash)?;
+        if is_occurrence(invite, exception.original_start) {
+            exceptions.insert(exception.original_start, exception);
+        }
+    }
+    Ok(exceptions)
+}
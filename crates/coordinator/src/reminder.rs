@@ -0,0 +1,188 @@
+use hdk::prelude::*;
+use hc_integrity_zome_invitations::*;
+use std::collections::BTreeSet;
+use crate::invite::{get_invitation_info, get_latest_invite, read_response_tag};
+use crate::utils::query_my_latest_records;
+use crate::recurrence::get_occurrences;
+use crate::signals;
+
+//reminders are checked every minute and given at the first check after they are due
+pub const REMINDER_SCHEDULE: &str = "0 * * * * * *";
+
+//offsets are in microseconds before the start time, an empty list turns reminders off
+#[hdk_extern]
+pub fn set_reminder_offsets(offsets: Vec<i64>) -> ExternResult<Vec<i64>> {
+    let reminder_settings = ReminderSettings { offsets };
+    match get_my_reminder_settings()? {
+        Some(record) => update_entry(record.action_address().clone(), &EntryTypes::ReminderSettings(reminder_settings.clone()))?,
+        None => create_entry(&EntryTypes::ReminderSettings(reminder_settings.clone()))?,
+    };
+    Ok(reminder_settings.offsets)
+}
+
+
+//the default offsets are one day and 15 minutes
+#[hdk_extern]
+pub fn get_reminder_offsets(_: ()) -> ExternResult<Vec<i64>> {
+    let record = match get_my_reminder_settings()? {
+        Some(record) => record,
+        None => return Ok(DEFAULT_REMINDER_OFFSETS.to_vec()),
+    };
+    let reminder_settings: ReminderSettings = record.entry().to_app_option().map_err(|e| wasm_error!(e))?
+    .ok_or(wasm_error!(WasmErrorInner::Guest(String::from("Could not de-serialize the ReminderSettings Entry in the record"))))?;
+    Ok(reminder_settings.offsets)
+}
+
+
+//scheduled on init, it keeps its schedule by returning it
+#[hdk_extern(infallible)]
+pub fn send_reminders(_: Option<Schedule>) -> Option<Schedule> {
+    if let Err(err) = fire_due_reminders() {
+        error!("Error sending reminders: {:?}", err);
+    }
+    Some(Schedule::Persisted(REMINDER_SCHEDULE.into()))
+}
+
+
+
+
+//************ Helpers **************************
+
+fn get_my_reminder_settings() -> ExternResult<Option<Record>> {
+    Ok(query_my_latest_records(UnitEntryTypes::ReminderSettings.try_into()?)?
+        .into_values()
+        .max_by_key(|record| record.action().timestamp()))
+}
+
+fn get_fired_reminders() -> ExternResult<Vec<FiredReminder>> {
+    let records = query(
+        ChainQueryFilter::new()
+            .entry_type(UnitEntryTypes::FiredReminder.try_into()?)
+            .include_entries(true),
+    )?;
+    let mut fired_reminders: Vec<FiredReminder> = vec![];
+    for record in records.into_iter() {
+        if let Some(fired_reminder) = record.entry().to_app_option::<FiredReminder>().map_err(|e| wasm_error!(e))? {
+            fired_reminders.push(fired_reminder);
+        }
+    }
+    Ok(fired_reminders)
+}
+
+//only the closest reminder that is due is given, eg. an invitation accepted 10 minutes before
+//it starts gets the 15 minute reminder but not the one day reminder.
+//this runs every minute, so the full InviteInfo is only read for invitations we accepted that have a reminder due
+fn fire_due_reminders() -> ExternResult<()> {
+    let my_pub_key: AgentPubKey = agent_info()?.agent_latest_pubkey;
+    let offsets = get_reminder_offsets(())?;
+    let max_offset = match offsets.iter().max() {
+        Some(max_offset) => *max_offset,
+        None => return Ok(()),
+    };
+    let now = sys_time()?;
+    let window_end = Timestamp::from_micros(now.as_micros().saturating_add(max_offset));
+    let due_offset = |start_time: Timestamp| offsets.iter()
+        .filter(|offset| now.as_micros() >= start_time.as_micros() - **offset)
+        .min()
+        .cloned();
+    let mut fired_reminders = get_fired_reminders()?;
+    for creation_hash in get_my_accepted_invitations()?.into_iter() {
+        if let Some(start_times) = get_candidate_starts(&creation_hash, now, window_end)? {
+            let has_due_reminder = start_times.into_iter().any(|start_time| match due_offset(start_time) {
+                Some(offset) => !is_fired(&fired_reminders, &creation_hash, start_time, offset),
+                None => false,
+            });
+            if !has_due_reminder {
+                continue;
+            }
+        }
+        let invite_info = get_invitation_info(&creation_hash)?;
+        if invite_info.cancelled {
+            continue;
+        }
+        for start_time in get_upcoming_accepted_starts(&my_pub_key, &invite_info, now, window_end)?.into_iter() {
+            let offset = match due_offset(start_time) {
+                Some(offset) => offset,
+                None => continue,
+            };
+            if is_fired(&fired_reminders, &creation_hash, start_time, offset) {
+                continue;
+            }
+            let fired_reminder = FiredReminder {
+                creation_hash: creation_hash.clone(),
+                start_time,
+                offset,
+            };
+            create_entry(&EntryTypes::FiredReminder(fired_reminder.clone()))?;
+            fired_reminders.push(fired_reminder);
+            signals::invitation_reminder(invite_info.clone(), start_time, offset)?;
+        }
+    }
+    Ok(())
+}
+
+fn is_fired(fired_reminders: &[FiredReminder], creation_hash: &ActionHash, start_time: Timestamp, offset: i64) -> bool {
+    fired_reminders.iter().any(|fired_reminder| {
+        &fired_reminder.creation_hash == creation_hash
+            && fired_reminder.start_time == start_time
+            && fired_reminder.offset <= offset
+    })
+}
+
+//invitations we ever accepted, read from the response links on our own source chain.
+//whether the acceptance still counts is left to the InviteInfo
+fn get_my_accepted_invitations() -> ExternResult<BTreeSet<ActionHash>> {
+    let records = query(ChainQueryFilter::new().action_type(ActionType::CreateLink))?;
+    let mut accepted: BTreeSet<ActionHash> = BTreeSet::new();
+    for record in records.into_iter() {
+        if let Action::CreateLink(create_link) = record.action() {
+            if !matches!(LinkTypes::from_type(create_link.zome_index, create_link.link_type), Ok(Some(LinkTypes::InviteToAgent))) {
+                continue;
+            }
+            let creation_hash = match ActionHash::try_from(create_link.base_address.clone()) {
+                Ok(creation_hash) => creation_hash,
+                Err(_) => continue,
+            };
+            if read_response_tag(create_link.tag.clone(), &creation_hash).is_some_and(|tag| tag.response == "accepted") {
+                accepted.insert(creation_hash);
+            }
+        }
+    }
+    Ok(accepted)
+}
+
+//start times within the window read from the latest version of the invitation only.
+//None when an occurrence may have been moved by an exception, the occurrences then have to be read
+fn get_candidate_starts(creation_hash: &ActionHash, now: Timestamp, window_end: Timestamp) -> ExternResult<Option<Vec<Timestamp>>> {
+    let invite = get_latest_invite(creation_hash)?;
+    if invite.recurrence.is_some() {
+        if !get_links(creation_hash.clone(), LinkTypes::InviteToException, None)?.is_empty() {
+            return Ok(None);
+        }
+        return Ok(Some(expand_occurrences(&invite, now, window_end)));
+    }
+    Ok(Some(invite.start_time
+        .filter(|start_time| *start_time > now && *start_time <= window_end)
+        .into_iter()
+        .collect()))
+}
+
+//start times within the largest offset of the invitation, or of the occurrences of a recurring one, that we accepted
+fn get_upcoming_accepted_starts(my_pub_key: &AgentPubKey, invite_info: &InviteInfo, now: Timestamp, window_end: Timestamp) -> ExternResult<Vec<Timestamp>> {
+    if invite_info.invitation.recurrence.is_some() {
+        let occurrences = get_occurrences(OccurrencesInput {
+            creation_hash: invite_info.creation_hash.clone(),
+            window_start: now,
+            window_end,
+        })?;
+        Ok(occurrences.into_iter()
+            .filter(|occurrence| !occurrence.cancelled && occurrence.invitees_who_accepted.contains(my_pub_key))
+            .map(|occurrence| occurrence.start_time)
+            .collect())
+    } else {
+        Ok(invite_info.invitation.start_time
+            .filter(|start_time| *start_time > now && *start_time <= window_end && invite_info.invitees_who_accepted.contains(my_pub_key))
+            .into_iter()
+            .collect())
+    }
+}
@@ -1,18 +1,88 @@
 use hdk::prelude::*;
 use hc_integrity_zome_invitations::*;
+use crate::invite::get_invite_hosts;
+use crate::notification::record_notifications;
+use crate::delivery::deliver_invitation_notices;
+use crate::encryption::without_decrypted_details;
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(tag = "type")]
 pub enum Signal {
-    InvitationAccepted {action: SignedActionHashed, data: InviteInfo },
+    InvitationAccepted {action: SignedActionHashed, data: InviteInfo, response: InviteResponse},
     InvitationReceived {action: SignedActionHashed, data: InviteInfo},
-    InvitationRejected {action: SignedActionHashed, data: InviteInfo},
+    InvitationRejected {action: SignedActionHashed, data: InviteInfo, response: InviteResponse},
+    InvitationTentative {action: SignedActionHashed, data: InviteInfo, response: InviteResponse},
     InvitationUpdated {action: SignedActionHashed, data: InviteInfo},
+    InvitationCancelled {action: SignedActionHashed, data: InviteInfo},
+    InvitationClosed {action: SignedActionHashed, data: InviteInfo},
+    InvitationRevoked {action: SignedActionHashed, data: InviteInfo},
+    ResponseChanged {action: SignedActionHashed, data: InviteInfo, response: InviteResponse},
+    WaitlistPromoted {action: SignedActionHashed, data: InviteInfo},
+    QuorumReached {action: SignedActionHashed, data: InviteInfo},
+    TimeProposed {action: SignedActionHashed, data: InviteInfo, proposal: TimeProposalInfo},
+    OccurrenceChanged {action: SignedActionHashed, data: InviteInfo, exception: OccurrenceException},
+    SlotFinalized {action: SignedActionHashed, data: InviteInfo},
+    InvitationCheckedIn {action: SignedActionHashed, data: InviteInfo},
+    InvitationReminder {data: InviteInfo, start_time: Timestamp, offset: i64},
+
+}
 
+impl Signal {
+    //the type, action and invitation of a signal sent to other agents, local signals are not notifications
+    fn notification(&self) -> Option<(&'static str, &SignedActionHashed, &InviteInfo)> {
+        match self {
+            Signal::InvitationAccepted { action, data, .. } => Some(("InvitationAccepted", action, data)),
+            Signal::InvitationReceived { action, data, .. } => Some(("InvitationReceived", action, data)),
+            Signal::InvitationRejected { action, data, .. } => Some(("InvitationRejected", action, data)),
+            Signal::InvitationTentative { action, data, .. } => Some(("InvitationTentative", action, data)),
+            Signal::InvitationUpdated { action, data, .. } => Some(("InvitationUpdated", action, data)),
+            Signal::InvitationCancelled { action, data, .. } => Some(("InvitationCancelled", action, data)),
+            Signal::InvitationClosed { action, data, .. } => Some(("InvitationClosed", action, data)),
+            Signal::InvitationRevoked { action, data, .. } => Some(("InvitationRevoked", action, data)),
+            Signal::ResponseChanged { action, data, .. } => Some(("ResponseChanged", action, data)),
+            Signal::WaitlistPromoted { action, data, .. } => Some(("WaitlistPromoted", action, data)),
+            Signal::QuorumReached { action, data, .. } => Some(("QuorumReached", action, data)),
+            Signal::TimeProposed { action, data, .. } => Some(("TimeProposed", action, data)),
+            Signal::OccurrenceChanged { action, data, .. } => Some(("OccurrenceChanged", action, data)),
+            Signal::SlotFinalized { action, data, .. } => Some(("SlotFinalized", action, data)),
+            Signal::InvitationCheckedIn { action, data, .. } => Some(("InvitationCheckedIn", action, data)),
+            Signal::InvitationReminder { .. } => None,
+        }
+    }
+}
+
+//what the UI receives, local signals are about the actions of our own agent so other windows of the UI stay in sync
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SignalEnvelope {
+    #[serde(flatten)]
+    pub signal: Signal,
+    pub local: bool,
+}
+
+pub fn emit_to_ui(signal: Signal, local: bool) -> ExternResult<()> {
+    emit_signal(SignalEnvelope { signal, local })
+}
+
+//remote signals are lost when a recipient is offline, so they are also left in the inbox of every recipient.
+//the signal is returned by the signal functions, signal_action emits it once to our own UI
+fn send_signal(signal: Signal, recipients: Vec<AgentPubKey>) -> ExternResult<()> {
+    if let Some((kind, action, data)) = signal.notification() {
+        record_notifications(kind, &action.hashed.hash, &data.creation_hash, &recipients)?;
+    }
+    remote_signal(signal, recipients)
+}
+
+//invitations are delivered with an acknowledgement instead, so that hosts know who received them
+fn send_acknowledged_signal(signal: Signal, recipients: Vec<AgentPubKey>) -> ExternResult<()> {
+    if let Some((kind, action, data)) = signal.notification() {
+        record_notifications(kind, &action.hashed.hash, &data.creation_hash, &recipients)?;
+        deliver_invitation_notices(&signal, &action.hashed.hash, &data.creation_hash, &recipients)?;
+    }
+    Ok(())
 }
 
 //broadcast to everyone inviter
-pub fn invitation_received(action_data: SignedActionHashed, invite_detail:InviteInfo) -> ExternResult<bool> {
+pub fn invitation_received(action_data: SignedActionHashed, invite_detail:InviteInfo) -> ExternResult<Signal> {
     let my_pub_key: AgentPubKey = agent_info()?.agent_latest_pubkey;
 
     let signal: Signal = Signal::InvitationReceived {
@@ -26,55 +96,257 @@ pub fn invitation_received(action_data: SignedActionHashed, invite_detail:Invite
         .invitees
         .into_iter()
         .filter(|invitee| !AgentPubKey::from(invitee.clone()).eq(&my_pub_key))
-        .map(|agent_pub_key| agent_pub_key.into())
         .collect();
 
-    remote_signal(signal, send_signal_to)?;
-    Ok(true)
+    send_acknowledged_signal(signal.clone(), send_signal_to)?;
+    Ok(signal)
 }
 
-//broadcast to everyone except updater - consider an emit_signal for UI
-pub fn invitation_updated(action_data: SignedActionHashed, invite_detail:InviteInfo) -> ExternResult<bool> {
+//broadcast to everyone except updater
+//invitees added by the update are sent a received signal and removed ones a revoked signal instead
+pub fn invitation_updated(action_data: SignedActionHashed, invite_detail:InviteInfo, previous_invitees: Vec<AgentPubKey>) -> ExternResult<Signal> {
     let my_pub_key: AgentPubKey = agent_info()?.agent_latest_pubkey;
 
-    let signal: Signal = Signal::InvitationUpdated {
-        action: action_data,
-        data: invite_detail.clone()
-    };
-
-    let send_signal_to: Vec<AgentPubKey> = invite_detail
+    let (send_signal_to, added_invitees): (Vec<AgentPubKey>, Vec<AgentPubKey>) = invite_detail
         .clone()
         .invitation
         .invitees
         .into_iter()
         .filter(|invitee| !AgentPubKey::from(invitee.clone()).eq(&my_pub_key))
-        .map(|agent_pub_key| agent_pub_key.into())
+        .partition(|invitee| previous_invitees.contains(invitee));
+
+    //agents that joined with the invite code are not in the invitees of the entry
+    let mut send_signal_to = send_signal_to;
+    send_signal_to.extend(invite_detail.invitees_redeemed.iter().filter(|invitee| **invitee != my_pub_key).cloned());
+
+    let removed_invitees: Vec<AgentPubKey> = previous_invitees
+        .into_iter()
+        .filter(|invitee| !invitee.eq(&my_pub_key) && !invite_detail.invitation.invitees.contains(invitee))
         .collect();
 
-    remote_signal(signal, send_signal_to)?;
-    return Ok(true)
+    let signal: Signal = Signal::InvitationUpdated {
+        action: action_data.clone(),
+        data: invite_detail.clone()
+    };
+    send_signal(signal.clone(), send_signal_to)?;
+
+    if !added_invitees.is_empty() {
+        let received_signal: Signal = Signal::InvitationReceived {
+            action: action_data.clone(),
+            data: invite_detail.clone()
+        };
+        send_acknowledged_signal(received_signal, added_invitees)?;
+    }
+
+    //removed invitees are no longer recipients of the encrypted details, they only get the ciphertext
+    if !removed_invitees.is_empty() {
+        let revoked_signal: Signal = Signal::InvitationRevoked {
+            action: action_data,
+            data: InviteInfo {
+                invitation: without_decrypted_details(invite_detail.invitation.clone()),
+                ..invite_detail
+            }
+        };
+        send_signal(revoked_signal, removed_invitees)?;
+    }
+    Ok(signal)
 }
 
-//signal only from invitee to inviter to avoid group noise
-pub fn invitation_accepted(action_data: SignedActionHashed, invite_detail:InviteInfo) -> ExternResult<bool> {
+//signal only from invitee to the hosts to avoid group noise
+pub fn invitation_accepted(action_data: SignedActionHashed, invite_detail:InviteInfo, response: InviteResponse) -> ExternResult<Signal> {
     let signal: Signal = Signal::InvitationAccepted {
+        action: action_data,
+        data: invite_detail.clone(),
+        response
+    };
+
+    let send_signal_to: Vec<AgentPubKey> = get_signalled_hosts(&invite_detail)?;
+    send_signal(signal.clone(), send_signal_to)?;
+    Ok(signal)
+}
+
+//signal only from invitee to the hosts to avoid group noise
+pub fn invitation_rejected(action_data: SignedActionHashed, invite_detail:InviteInfo, response: InviteResponse) -> ExternResult<Signal> {
+    let signal: Signal = Signal::InvitationRejected {
+        action: action_data,
+        data: invite_detail.clone(),
+        response
+    };
+
+    let send_signal_to: Vec<AgentPubKey> = get_signalled_hosts(&invite_detail)?;
+    send_signal(signal.clone(), send_signal_to)?;
+    Ok(signal)
+}
+
+//broadcast to every invitee except the canceller
+pub fn invitation_cancelled(action_data: SignedActionHashed, invite_detail:InviteInfo) -> ExternResult<Signal> {
+    let signal: Signal = Signal::InvitationCancelled {
         action: action_data,
         data: invite_detail.clone()
     };
 
-    let send_signal_to: Vec<AgentPubKey> = vec![invite_detail.author];
-    remote_signal(signal, send_signal_to)?;
+    let send_signal_to: Vec<AgentPubKey> = get_signalled_invitees(&invite_detail)?;
+
+    send_signal(signal.clone(), send_signal_to)?;
+    Ok(signal)
+}
+
+//broadcast to every invitee except the author that closed the invitation
+pub fn invitation_closed(action_data: SignedActionHashed, invite_detail:InviteInfo) -> ExternResult<Signal> {
+    let signal: Signal = Signal::InvitationClosed {
+        action: action_data,
+        data: invite_detail.clone()
+    };
+
+    let send_signal_to: Vec<AgentPubKey> = get_signalled_invitees(&invite_detail)?;
+
+    send_signal(signal.clone(), send_signal_to)?;
+    Ok(signal)
+}
+
+//signal only from invitee to the hosts to avoid group noise
+pub fn invitation_tentative(action_data: SignedActionHashed, invite_detail:InviteInfo, response: InviteResponse) -> ExternResult<Signal> {
+    let signal: Signal = Signal::InvitationTentative {
+        action: action_data,
+        data: invite_detail.clone(),
+        response
+    };
+
+    let send_signal_to: Vec<AgentPubKey> = get_signalled_hosts(&invite_detail)?;
+    send_signal(signal.clone(), send_signal_to)?;
+    Ok(signal)
+}
+
+//signal only from invitee to the hosts when an earlier response is superseded
+pub fn response_changed(action_data: SignedActionHashed, invite_detail:InviteInfo, response: InviteResponse) -> ExternResult<Signal> {
+    let signal: Signal = Signal::ResponseChanged {
+        action: action_data,
+        data: invite_detail.clone(),
+        response
+    };
+
+    let send_signal_to: Vec<AgentPubKey> = get_signalled_hosts(&invite_detail)?;
+    send_signal(signal.clone(), send_signal_to)?;
+    Ok(signal)
+}
+
+//signal the invitees who moved up from the waitlist after a place became free
+pub fn waitlist_promoted(action_data: SignedActionHashed, invite_detail:InviteInfo, promoted_invitees: Vec<AgentPubKey>) -> ExternResult<bool> {
+    if promoted_invitees.is_empty() {
+        return Ok(false);
+    }
+    let signal: Signal = Signal::WaitlistPromoted {
+        action: action_data,
+        data: invite_detail
+    };
+
+    send_signal(signal, promoted_invitees)?;
     Ok(true)
 }
 
-//signal only from invitee to inviter to avoid group noise
-pub fn invitation_rejected(action_data: SignedActionHashed, invite_detail:InviteInfo) -> ExternResult<bool> {
-    let signal: Signal = Signal::InvitationRejected {
+//signal only from invitee to the hosts, the other invitees see the new time once a host accepts it
+pub fn time_proposed(action_data: SignedActionHashed, invite_detail:InviteInfo, proposal: TimeProposalInfo) -> ExternResult<Signal> {
+    let signal: Signal = Signal::TimeProposed {
+        action: action_data,
+        data: invite_detail.clone(),
+        proposal
+    };
+
+    let send_signal_to: Vec<AgentPubKey> = get_signalled_hosts(&invite_detail)?;
+    send_signal(signal.clone(), send_signal_to)?;
+    Ok(signal)
+}
+
+//broadcast to every invitee except the host that cancelled or moved the occurrence
+pub fn occurrence_changed(action_data: SignedActionHashed, invite_detail:InviteInfo, exception: OccurrenceException) -> ExternResult<Signal> {
+    let signal: Signal = Signal::OccurrenceChanged {
+        action: action_data,
+        data: invite_detail.clone(),
+        exception
+    };
+
+    let send_signal_to: Vec<AgentPubKey> = get_signalled_invitees(&invite_detail)?;
+
+    send_signal(signal.clone(), send_signal_to)?;
+    Ok(signal)
+}
+
+//broadcast to every invitee and host once enough invitees accepted for the invitation to happen
+pub fn quorum_reached(action_data: SignedActionHashed, invite_detail:InviteInfo) -> ExternResult<bool> {
+    let signal: Signal = Signal::QuorumReached {
         action: action_data,
         data: invite_detail.clone()
     };
 
-    let send_signal_to: Vec<AgentPubKey> = vec![invite_detail.author];
-    remote_signal(signal, send_signal_to)?;
+    let mut send_signal_to: Vec<AgentPubKey> = get_signalled_hosts(&invite_detail)?;
+    for invitee in get_signalled_invitees(&invite_detail)?.into_iter() {
+        if !send_signal_to.contains(&invitee) {
+            send_signal_to.push(invitee);
+        }
+    }
+
+    send_signal(signal, send_signal_to)?;
     Ok(true)
 }
+
+//broadcast to every invitee and host once the author picked the slot of a polled invitation
+pub fn slot_finalized(action_data: SignedActionHashed, invite_detail:InviteInfo) -> ExternResult<Signal> {
+    let signal: Signal = Signal::SlotFinalized {
+        action: action_data,
+        data: invite_detail.clone()
+    };
+
+    let mut send_signal_to: Vec<AgentPubKey> = get_signalled_hosts(&invite_detail)?;
+    for invitee in get_signalled_invitees(&invite_detail)?.into_iter() {
+        if !send_signal_to.contains(&invitee) {
+            send_signal_to.push(invitee);
+        }
+    }
+
+    send_signal(signal.clone(), send_signal_to)?;
+    Ok(signal)
+}
+
+//signal only from invitee to the hosts, who follow the attendance
+pub fn invitation_checked_in(action_data: SignedActionHashed, invite_detail:InviteInfo) -> ExternResult<Signal> {
+    let signal: Signal = Signal::InvitationCheckedIn {
+        action: action_data,
+        data: invite_detail.clone()
+    };
+
+    let send_signal_to: Vec<AgentPubKey> = get_signalled_hosts(&invite_detail)?;
+    send_signal(signal.clone(), send_signal_to)?;
+    Ok(signal)
+}
+
+//emitted only to our own UI, the invitation or one of its occurrences starts within offset microseconds
+pub fn invitation_reminder(invite_detail:InviteInfo, start_time: Timestamp, offset: i64) -> ExternResult<bool> {
+    let signal: Signal = Signal::InvitationReminder {
+        data: invite_detail,
+        start_time,
+        offset
+    };
+
+    emit_to_ui(signal, true)?;
+    Ok(true)
+}
+
+//every host of the invitation except the one sending the signal
+fn get_signalled_hosts(invite_detail: &InviteInfo) -> ExternResult<Vec<AgentPubKey>> {
+    let my_pub_key: AgentPubKey = agent_info()?.agent_latest_pubkey;
+    Ok(get_invite_hosts(&invite_detail.author, &invite_detail.invitation)
+        .into_iter()
+        .filter(|host| !host.eq(&my_pub_key))
+        .collect())
+}
+
+//every invitee, including those that joined with the invite code, except the one sending the signal
+fn get_signalled_invitees(invite_detail: &InviteInfo) -> ExternResult<Vec<AgentPubKey>> {
+    let my_pub_key: AgentPubKey = agent_info()?.agent_latest_pubkey;
+    Ok(invite_detail.invitation.invitees
+        .iter()
+        .chain(invite_detail.invitees_redeemed.iter())
+        .filter(|invitee| **invitee != my_pub_key)
+        .cloned()
+        .collect())
+}
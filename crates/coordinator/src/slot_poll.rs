@@ -0,0 +1,94 @@
+use std::collections::BTreeMap;
+use hdk::prelude::*;
+use hc_integrity_zome_invitations::*;
+use crate::invite::{get_invitation_info, get_latest_record, update_invite_entry};
+
+//invitees vote yes, maybe or no on each slot, voting again on a slot replaces the earlier vote
+#[hdk_extern]
+pub fn vote_on_slot(input: SlotVoteInput) -> ExternResult<ActionHash> {
+    let my_pub_key: AgentPubKey = agent_info()?.agent_latest_pubkey;
+    let entry_info = get_invitation_info(&input.creation_hash)?;
+    if entry_info.cancelled {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from("This invitation has been cancelled"))));
+    }
+    if entry_info.invitation.closed || !is_slot_poll_open(&entry_info.invitation) {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from("This invitation is not polling its time"))));
+    }
+    if !entry_info.invitation.slots.contains(&input.slot) {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from("This invitation has no such slot"))));
+    }
+    if !entry_info.invitation.invitees.contains(&my_pub_key) {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from("Only invitees can vote on the slots of an invitation"))));
+    }
+    let last_invite_record = get_latest_record(input.creation_hash.clone())?;
+    let vote_tag = SlotVoteTag {
+        invite_action_hash: last_invite_record.action_address().clone(),
+        slot: input.slot,
+        vote: input.vote,
+    };
+    create_link(
+        input.creation_hash,
+        my_pub_key,
+        LinkTypes::InviteToSlotVote,
+        LinkTag::try_from(vote_tag)?,
+    )
+}
+
+
+//the author picks a slot, it becomes the time of the invitation and ends the poll
+#[hdk_extern]
+pub fn finalize_slot(input: FinalizeSlotInput) -> ExternResult<InviteInfo> {
+    let my_pub_key: AgentPubKey = agent_info()?.agent_latest_pubkey;
+    let entry_info = get_invitation_info(&input.creation_hash)?;
+    if entry_info.author != my_pub_key {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from("Only the author can finalize a slot"))));
+    }
+    if !is_slot_poll_open(&entry_info.invitation) {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from("This invitation is not polling its time"))));
+    }
+    if !entry_info.invitation.slots.contains(&input.slot) {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from("This invitation has no such slot"))));
+    }
+    let mut invite = entry_info.invitation;
+    invite.start_time = Some(input.slot.start_time);
+    invite.end_time = input.slot.end_time;
+    update_invite_entry(input.creation_hash, invite)
+}
+
+
+
+
+//************ Helpers **************************
+
+//the latest vote of every invitee on each slot of the invitation
+pub fn get_slot_tallies(create_action_hash: &ActionHash, invite: &Invite) -> ExternResult<Vec<SlotTally>> {
+    if invite.slots.is_empty() {
+        return Ok(vec![]);
+    }
+    let mut links = get_links(create_action_hash.clone(), LinkTypes::InviteToSlotVote, None)?;
+    links.sort_by(|a, b| a.timestamp.cmp(&b.timestamp).then(a.create_link_hash.cmp(&b.create_link_hash)));
+    let mut votes: BTreeMap<(usize, AgentPubKey), String> = BTreeMap::new();
+    for link in links.into_iter() {
+        let voter = AgentPubKey::try_from(link.target).map_err(|err| wasm_error!(err))?;
+        let vote_tag = SlotVoteTag::try_from(link.tag)?;
+        if let Some(slot_index) = invite.slots.iter().position(|slot| slot == &vote_tag.slot) {
+            votes.insert((slot_index, voter), vote_tag.vote);
+        }
+    }
+    let mut tallies: Vec<SlotTally> = invite.slots.iter()
+        .map(|slot| SlotTally { slot: slot.clone(), yes: vec![], maybe: vec![], no: vec![] })
+        .collect();
+    for ((slot_index, voter), vote) in votes.into_iter() {
+        //votes of agents that are no longer invited do not count
+        if !invite.invitees.contains(&voter) {
+            continue;
+        }
+        let tally = &mut tallies[slot_index];
+        match vote.as_str() {
+            "yes" => tally.yes.push(voter),
+            "maybe" => tally.maybe.push(voter),
+            _ => tally.no.push(voter),
+        }
+    }
+    Ok(tallies)
+}
@@ -0,0 +1,80 @@
+use hdk::prelude::*;
+use hc_integrity_zome_invitations::*;
+use crate::invite::{get_invitation_info, get_invite_hosts, get_latest_record, update_invite_entry};
+
+//an invitee that cannot make it at the proposed time suggests another one to the hosts
+#[hdk_extern]
+pub fn propose_new_time(input: TimeProposalInput) -> ExternResult<TimeProposalInfo> {
+    let entry_info = get_invitation_info(&input.creation_hash)?;
+    if entry_info.cancelled {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from("This invitation has been cancelled"))));
+    }
+    if entry_info.invitation.closed {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from("This invitation is closed"))));
+    }
+    let last_invite_record = get_latest_record(input.creation_hash.clone())?;
+    let proposal = TimeProposal {
+        creation_hash: input.creation_hash.clone(),
+        invite_action_hash: last_invite_record.action_address().clone(),
+        start_time: input.start_time,
+        end_time: input.end_time,
+        note: input.note,
+    };
+    let proposal_hash = create_entry(&EntryTypes::TimeProposal(proposal))?;
+    create_link(
+        input.creation_hash,
+        proposal_hash.clone(),
+        LinkTypes::InviteToProposal,
+        (),
+    )?;
+    get_time_proposal_info(&proposal_hash)
+}
+
+
+#[hdk_extern]
+pub fn get_time_proposals(original_action_hash: ActionHash) -> ExternResult<Vec<TimeProposalInfo>> {
+    let links = get_links(original_action_hash, LinkTypes::InviteToProposal, None)?;
+    let mut proposals: Vec<TimeProposalInfo> = vec![];
+    for link in links.into_iter() {
+        let proposal_hash = ActionHash::try_from(link.target).map_err(|err| wasm_error!(err))?;
+        proposals.push(get_time_proposal_info(&proposal_hash)?);
+    }
+    Ok(proposals)
+}
+
+
+//a host takes over the proposed time with a new version of the invitation,
+//responses given to the old time no longer count and those invitees are asked again
+#[hdk_extern]
+pub fn accept_proposal(proposal_hash: ActionHash) -> ExternResult<InviteInfo> {
+    let my_pub_key: AgentPubKey = agent_info()?.agent_latest_pubkey;
+    let proposal_info = get_time_proposal_info(&proposal_hash)?;
+    let entry_info = get_invitation_info(&proposal_info.proposal.creation_hash)?;
+    if !get_invite_hosts(&entry_info.author, &entry_info.invitation).contains(&my_pub_key) {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from("Only the author or a co-host of the invitation can accept a proposal"))));
+    }
+    let updated_invite = Invite {
+        start_time: Some(proposal_info.proposal.start_time),
+        end_time: proposal_info.proposal.end_time,
+        ..entry_info.invitation
+    };
+    update_invite_entry(entry_info.creation_hash, updated_invite)
+}
+
+
+
+
+//************ Helpers **************************
+
+pub fn get_time_proposal_info(proposal_hash: &ActionHash) -> ExternResult<TimeProposalInfo> {
+    let record = get(proposal_hash.clone(), GetOptions::default())?
+    .ok_or(wasm_error!(WasmErrorInner::Guest(String::from("Could not find the time proposal record"))))?;
+    let proposal: TimeProposal = record.entry().to_app_option().map_err(|e| wasm_error!(e))?
+    .ok_or(wasm_error!(WasmErrorInner::Guest(String::from("Could not de-serialize the TimeProposal Entry in the record"))))?;
+    Ok(TimeProposalInfo {
+        proposal,
+        proposal_hash: proposal_hash.clone(),
+        author: record.action().author().clone(),
+        timestamp: record.action().timestamp(),
+    })
+}
@@ -0,0 +1,33 @@
+use std::collections::BTreeMap;
+use hdk::prelude::*;
+
+//private entries can only be read from our own chain, so the update and delete chains are followed there.
+//latest record of every entry of the type that we created and did not delete, by the hash of its creation
+pub fn query_my_latest_records(entry_type: EntryType) -> ExternResult<BTreeMap<ActionHash, Record>> {
+    let records = query(
+        ChainQueryFilter::new()
+            .entry_type(entry_type)
+            .include_entries(true),
+    )?;
+    let mut creation_hashes: BTreeMap<ActionHash, ActionHash> = BTreeMap::new();
+    let mut latest_records: BTreeMap<ActionHash, Record> = BTreeMap::new();
+    for record in records.into_iter() {
+        let creation_hash = match record.action() {
+            Action::Update(update) => match creation_hashes.get(&update.original_action_address) {
+                Some(creation_hash) => creation_hash.clone(),
+                None => continue,
+            },
+            _ => record.action_address().clone(),
+        };
+        creation_hashes.insert(record.action_address().clone(), creation_hash.clone());
+        latest_records.insert(creation_hash, record);
+    }
+    for record in query(ChainQueryFilter::new().action_type(ActionType::Delete))?.into_iter() {
+        if let Action::Delete(delete) = record.action() {
+            if let Some(creation_hash) = creation_hashes.get(&delete.deletes_address) {
+                latest_records.remove(creation_hash);
+            }
+        }
+    }
+    Ok(latest_records)
+}
@@ -0,0 +1,122 @@
+use hdi::prelude::*;
+use crate::{must_get_invite_version, UnitEntryTypes};
+
+//an acceptance countersigned by the invitee and the author of the invitation, it lives on both their chains.
+//it is committed before the "accepted" response, which refers to it
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct Acceptance {
+    pub creation_hash: ActionHash,
+    pub invite_action_hash: ActionHash,
+    pub invitee: AgentPubKey,
+    pub inviter: AgentPubKey,
+}
+
+//Input DTO for the author of the invitation to join the countersigning session of an acceptance
+#[derive(Clone, Debug, Serialize, Deserialize, SerializedBytes)]
+pub struct AcceptancePreflightInput {
+    pub acceptance: Acceptance,
+    pub preflight_request: PreflightRequest,
+}
+
+//Input DTO for the author of the invitation to commit the countersigned acceptance
+#[derive(Clone, Debug, Serialize, Deserialize, SerializedBytes)]
+pub struct CountersignedAcceptanceInput {
+    pub acceptance: Acceptance,
+    pub responses: Vec<PreflightResponse>,
+}
+
+pub fn validate_create_acceptance(
+    _action: EntryCreationAction,
+    _acceptance: Acceptance,
+) -> ExternResult<ValidateCallbackResult> {
+    if _action.author() != &_acceptance.invitee && _action.author() != &_acceptance.inviter {
+        return Ok(ValidateCallbackResult::Invalid("only the invitee and the inviter can commit an acceptance".into()));
+    }
+    let invite = must_get_invite_version(&_acceptance.creation_hash, &_acceptance.invite_action_hash)?;
+    if !invite.countersign_acceptances {
+        return Ok(ValidateCallbackResult::Invalid("the invite does not countersign its acceptances".into()));
+    }
+    if must_get_action(_acceptance.creation_hash.clone())?.hashed.content.author() != &_acceptance.inviter {
+        Ok(ValidateCallbackResult::Invalid("the inviter must be the author of the invite".into()))
+    } else {
+        Ok(ValidateCallbackResult::Valid)
+    }
+}
+pub fn validate_update_acceptance(
+    _action: Update,
+    _acceptance: Acceptance,
+    _original_action: EntryCreationAction,
+    _original_acceptance: Acceptance,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid("Acceptances cannot be updated".into()))
+}
+pub fn validate_delete_acceptance(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_acceptance: Acceptance,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid("Acceptances cannot be deleted".into()))
+}
+
+//a countersigned acceptance of the invitation by the agent, an "accepted" response refers to it when the invite countersigns its acceptances
+pub fn is_acceptance_of(create_action_hash: &ActionHash, acceptance_hash: &ActionHash, agent: &AgentPubKey) -> ExternResult<bool> {
+    let record = must_get_valid_record(acceptance_hash.clone())?;
+    match record.entry().as_option() {
+        Some(Entry::CounterSign(_, entry_bytes)) => {
+            let acceptance = Acceptance::try_from(SerializedBytes::from(entry_bytes.to_owned())).map_err(|e| wasm_error!(e))?;
+            Ok(&acceptance.creation_hash == create_action_hash && &acceptance.invitee == agent)
+        }
+        _ => Ok(false),
+    }
+}
+
+//the flattened op only holds the app entry, so the countersignatures of an acceptance are checked on the op itself:
+//an acceptance must be countersigned by exactly its invitee and its inviter, and both signatures must be valid
+pub fn validate_acceptance_countersignatures(op: &Op) -> ExternResult<Option<ValidateCallbackResult>> {
+    let (entry_type, entry) = match op {
+        Op::StoreEntry(store_entry) => (store_entry.action.hashed.content.entry_type(), &store_entry.entry),
+        Op::StoreRecord(store_record) => match (store_record.record.action().entry_type(), store_record.record.entry().as_option()) {
+            (Some(entry_type), Some(entry)) => (entry_type, entry),
+            _ => return Ok(None),
+        },
+        _ => return Ok(None),
+    };
+    let is_acceptance = match entry_type {
+        EntryType::App(app_entry_def) => matches!(
+            UnitEntryTypes::try_from(ScopedEntryDefIndex {
+                zome_index: app_entry_def.zome_index,
+                zome_type: app_entry_def.entry_index,
+            }),
+            Ok(UnitEntryTypes::Acceptance)
+        ),
+        _ => false,
+    };
+    if !is_acceptance {
+        return Ok(None);
+    }
+    let (session_data, entry_bytes) = match entry {
+        Entry::CounterSign(session_data, entry_bytes) => (session_data, entry_bytes),
+        _ => return Ok(Some(ValidateCallbackResult::Invalid("an acceptance must be countersigned".into()))),
+    };
+    let acceptance = Acceptance::try_from(SerializedBytes::from(entry_bytes.to_owned())).map_err(|e| wasm_error!(e))?;
+    let signing_agents: Vec<&AgentPubKey> = session_data.signing_agents().collect();
+    if signing_agents.len() != 2 || !signing_agents.contains(&&acceptance.invitee) || !signing_agents.contains(&&acceptance.inviter) {
+        return Ok(Some(ValidateCallbackResult::Invalid("an acceptance must be countersigned by its invitee and inviter".into())));
+    }
+    for (agent_state, signature) in session_data.responses().iter() {
+        let agent = match signing_agents.get(*agent_state.agent_index() as usize) {
+            Some(agent) => (*agent).clone(),
+            None => return Ok(Some(ValidateCallbackResult::Invalid("a countersignature has no signing agent".into()))),
+        };
+        let signed_data = PreflightResponse::encode_fields_for_signature(session_data.preflight_request(), agent_state)
+            .map_err(|e| wasm_error!(e))?;
+        if !verify_signature_raw(agent, signature.clone(), signed_data)? {
+            return Ok(Some(ValidateCallbackResult::Invalid("a countersignature of the acceptance is not valid".into())));
+        }
+    }
+    if session_data.responses().len() != signing_agents.len() {
+        return Ok(Some(ValidateCallbackResult::Invalid("an acceptance must be signed by every signing agent".into())));
+    }
+    Ok(None)
+}
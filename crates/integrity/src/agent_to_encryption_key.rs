@@ -0,0 +1,42 @@
+use hdi::prelude::*;
+use crate::EncryptionKey;
+
+pub fn validate_create_link_agent_to_encryption_key(
+    _action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if base_address != AnyLinkableHash::from(_action.author.clone()) {
+        return Ok(ValidateCallbackResult::Invalid("agents can only publish their own encryption key".into()));
+    }
+    let action_hash = target_address.into_action_hash().ok_or(
+        wasm_error!(
+            WasmErrorInner::Guest(String::from("target address is not a compatible link hash"))
+        ),
+    )?;
+    let record = must_get_valid_record(action_hash)?;
+    let _encryption_key: EncryptionKey = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+            ),
+        )?;
+    if record.action().author() != &_action.author {
+        Ok(ValidateCallbackResult::Invalid("agents can only publish their own encryption key".into()))
+    } else {
+        Ok(ValidateCallbackResult::Valid)
+    }
+}
+pub fn validate_delete_link_agent_to_encryption_key(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid("Encryption keys cannot be unpublished".into()))
+}
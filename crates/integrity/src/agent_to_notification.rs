@@ -0,0 +1,68 @@
+use hdi::prelude::*;
+
+//the notification tag holds the type of the signal that was sent and the invitation it is about,
+//the link points from the recipient to the action that caused it
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, SerializedBytes)]
+pub struct NotificationTag {
+    pub kind: String,
+    pub creation_hash: ActionHash,
+}
+
+impl TryFrom<NotificationTag> for LinkTag {
+    type Error = WasmError;
+    fn try_from(notification_tag: NotificationTag) -> Result<Self, Self::Error> {
+        let bytes = SerializedBytes::try_from(notification_tag).map_err(|e| wasm_error!(e))?;
+        Ok(LinkTag::new(bytes.bytes().clone()))
+    }
+}
+
+impl TryFrom<LinkTag> for NotificationTag {
+    type Error = WasmError;
+    fn try_from(tag: LinkTag) -> Result<Self, Self::Error> {
+        NotificationTag::try_from(SerializedBytes::from(UnsafeBytes::from(tag.into_inner())))
+            .map_err(|e| wasm_error!(e))
+    }
+}
+
+//Output DTO for a notification in the inbox, the notification hash is the hash of its link
+#[derive(Clone, Debug, Serialize, Deserialize, SerializedBytes)]
+pub struct Notification {
+    pub notification_hash: ActionHash,
+    pub kind: String,
+    pub creation_hash: ActionHash,
+    pub action_hash: ActionHash,
+    pub sender: AgentPubKey,
+    pub timestamp: Timestamp,
+    pub read: bool,
+}
+
+//agents can only notify others about their own actions
+pub fn validate_create_link_agent_to_notification(
+    _action: CreateLink,
+    _base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if NotificationTag::try_from(_tag).is_err() {
+        return Ok(ValidateCallbackResult::Invalid("notification link tag is malformed".into()));
+    }
+    let action_hash = target_address.into_action_hash().ok_or(
+        wasm_error!(
+            WasmErrorInner::Guest(String::from("target address is not a compatible link hash"))
+        ),
+    )?;
+    if must_get_action(action_hash)?.hashed.content.author() != &_action.author {
+        Ok(ValidateCallbackResult::Invalid("agents can only notify about their own actions".into()))
+    } else {
+        Ok(ValidateCallbackResult::Valid)
+    }
+}
+pub fn validate_delete_link_agent_to_notification(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid("Notifications cannot be deleted, they are marked as read".into()))
+}
@@ -0,0 +1,53 @@
+use std::collections::BTreeMap;
+
+use hdi::prelude::*;
+
+//the x25519 public key an agent publishes so that private invitation details can be encrypted for them.
+//its secret key never leaves the keystore of the agent
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct EncryptionKey {
+    pub key: X25519PubKey,
+}
+
+//the location and details of an invitation, boxed from the key of the agent that wrote the version to each recipient
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct EncryptedDetails {
+    pub sender_key: X25519PubKey,
+    pub recipients: Vec<EncryptedDetailsRecipient>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct EncryptedDetailsRecipient {
+    pub agent: AgentPubKey,
+    pub data: XSalsa20Poly1305EncryptedData,
+}
+
+//what is encrypted for every recipient
+#[derive(Clone, Debug, Serialize, Deserialize, SerializedBytes)]
+pub struct PrivateInviteDetails {
+    pub location: Option<String>,
+    pub details: Option<BTreeMap<String,String>>,
+}
+
+pub fn validate_create_encryption_key(
+    _action: EntryCreationAction,
+    _encryption_key: EncryptionKey,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_update_encryption_key(
+    _action: Update,
+    _encryption_key: EncryptionKey,
+    _original_action: EntryCreationAction,
+    _original_encryption_key: EncryptionKey,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid("Encryption keys cannot be updated".into()))
+}
+pub fn validate_delete_encryption_key(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_encryption_key: EncryptionKey,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid("Encryption keys cannot be deleted".into()))
+}
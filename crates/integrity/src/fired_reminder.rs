@@ -0,0 +1,33 @@
+use hdi::prelude::*;
+
+//a reminder given for the invitation or the occurrence starting at start_time, so that it is not given again.
+//the offset is the one it was given for, in microseconds before the start time
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct FiredReminder {
+    pub creation_hash: ActionHash,
+    pub start_time: Timestamp,
+    pub offset: i64,
+}
+
+pub fn validate_create_fired_reminder(
+    _action: EntryCreationAction,
+    _fired_reminder: FiredReminder,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_update_fired_reminder(
+    _action: Update,
+    _fired_reminder: FiredReminder,
+    _original_action: EntryCreationAction,
+    _original_fired_reminder: FiredReminder,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid("Fired reminders cannot be updated".into()))
+}
+pub fn validate_delete_fired_reminder(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_fired_reminder: FiredReminder,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid("Fired reminders cannot be deleted".into()))
+}
@@ -0,0 +1,65 @@
+use hdi::prelude::*;
+
+//a named list of agents that can be invited at once, only its creator can change it
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct Group {
+    pub name: String,
+    pub members: Vec<AgentPubKey>,
+}
+
+//Input DTO from the UI to update a group, the group hash is the hash of its creation.
+//with propagate the membership changes are also made to the open invitations of the group that the updater hosts
+#[derive(Clone, Debug, Serialize, Deserialize, SerializedBytes)]
+pub struct UpdateGroupInput {
+    pub group_hash: ActionHash,
+    pub group: Group,
+    #[serde(default)]
+    pub propagate: bool,
+}
+
+//Output DTO for the UI
+#[derive(Clone, Debug, Serialize, Deserialize, SerializedBytes)]
+pub struct GroupInfo {
+    pub group: Group,
+    pub group_hash: ActionHash,
+    pub author: AgentPubKey,
+    pub timestamp: Timestamp,
+}
+
+pub fn validate_create_group(
+    _action: EntryCreationAction,
+    _group: Group,
+) -> ExternResult<ValidateCallbackResult> {
+    if _group.name.trim().is_empty() {
+        Ok(ValidateCallbackResult::Invalid("a group needs a name".into()))
+    } else {
+        Ok(ValidateCallbackResult::Valid)
+    }
+}
+pub fn validate_update_group(
+    _action: Update,
+    _group: Group,
+    _original_action: EntryCreationAction,
+    _original_group: Group,
+) -> ExternResult<ValidateCallbackResult> {
+    if _original_action.author() != &_action.author {
+        Ok(ValidateCallbackResult::Invalid("Only the creator of a group can update it".into()))
+    } else if _group.name.trim().is_empty() {
+        Ok(ValidateCallbackResult::Invalid("a group needs a name".into()))
+    } else {
+        Ok(ValidateCallbackResult::Valid)
+    }
+}
+pub fn validate_delete_group(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_group: Group,
+) -> ExternResult<ValidateCallbackResult> {
+    if _original_action.author() == &_action.author
+    {
+        Ok(ValidateCallbackResult::Valid)
+    } else {
+        Ok(ValidateCallbackResult::Invalid("Only the creator of a group can delete it".into()))
+    }
+}
@@ -0,0 +1,56 @@
+use hdi::prelude::*;
+use crate::{must_get_invite_version, Group};
+
+//the tag of a link from a group to an invitation holds the Invite version that referenced the group
+pub fn group_link_tag(invite_action_hash: &ActionHash) -> LinkTag {
+    LinkTag::new(invite_action_hash.get_raw_39().to_vec())
+}
+
+pub fn validate_create_link_group_to_invite(
+    _action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let group_hash = base_address.into_action_hash().ok_or(
+        wasm_error!(
+            WasmErrorInner::Guest(String::from("base address is not a compatible link hash"))
+        ),
+    )?;
+    let _group: Group = must_get_valid_record(group_hash.clone())?
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+            ),
+        )?;
+    let create_action_hash = target_address.into_action_hash().ok_or(
+        wasm_error!(
+            WasmErrorInner::Guest(String::from("target address is not a compatible link hash"))
+        ),
+    )?;
+    let invite_action_hash = match ActionHash::from_raw_39(_tag.into_inner()) {
+        Ok(invite_action_hash) => invite_action_hash,
+        Err(_) => return Ok(ValidateCallbackResult::Invalid("group link tag is malformed".into())),
+    };
+    let invite = must_get_invite_version(&create_action_hash, &invite_action_hash)?;
+    let author = must_get_action(create_action_hash)?.hashed.content.author().clone();
+    if !invite.groups.contains(&group_hash) {
+        Ok(ValidateCallbackResult::Invalid("the invite does not reference the group".into()))
+    } else if author != _action.author && !invite.co_hosts.contains(&_action.author) {
+        Ok(ValidateCallbackResult::Invalid("only the hosts of an invite can link it to a group".into()))
+    } else {
+        Ok(ValidateCallbackResult::Valid)
+    }
+}
+pub fn validate_delete_link_group_to_invite(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid("Invitations cannot be unlinked from a group".into()))
+}
@@ -0,0 +1,41 @@
+use hdi::prelude::*;
+
+//an invitation sent to a recipient with an acknowledgement, kept privately on the chain of the host that sent it.
+//notices that were not delivered are retried and updated once the recipient acknowledges them
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct InvitationNotice {
+    pub creation_hash: ActionHash,
+    pub action_hash: ActionHash,
+    pub recipient: AgentPubKey,
+    pub delivered: bool,
+}
+
+pub fn validate_create_invitation_notice(
+    _action: EntryCreationAction,
+    _invitation_notice: InvitationNotice,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_update_invitation_notice(
+    _action: Update,
+    _invitation_notice: InvitationNotice,
+    _original_action: EntryCreationAction,
+    _original_invitation_notice: InvitationNotice,
+) -> ExternResult<ValidateCallbackResult> {
+    if _invitation_notice.creation_hash != _original_invitation_notice.creation_hash
+        || _invitation_notice.action_hash != _original_invitation_notice.action_hash
+        || _invitation_notice.recipient != _original_invitation_notice.recipient
+    {
+        Ok(ValidateCallbackResult::Invalid("only the delivery of a notice can be updated".into()))
+    } else {
+        Ok(ValidateCallbackResult::Valid)
+    }
+}
+pub fn validate_delete_invitation_notice(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_invitation_notice: InvitationNotice,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid("Invitation notices cannot be deleted".into()))
+}
@@ -1,24 +1,89 @@
 use std::collections::BTreeMap;
 
 use hdi::prelude::*;
+use crate::{validate_recurrence_rule, EncryptedDetails, RecurrenceRule, ResponseReason, SlotTally};
 #[hdk_entry_helper]
 #[derive(Clone, PartialEq)]
 pub struct Invite {
     pub invitees: Vec<AgentPubKey>,
+    pub invitee_roles: Vec<InviteeRole>,
+    pub co_hosts: Vec<AgentPubKey>,
     pub location: Option<String>,
     pub start_time: Option<Timestamp>,
     pub end_time: Option<Timestamp>,
     pub details: Option<BTreeMap<String,String>>,
+    pub rsvp_deadline: Option<Timestamp>,
+    pub max_attendees: Option<u32>,
+    pub min_attendees: Option<u32>,
+    pub allow_guests: bool,
+    pub max_guests_per_invitee: Option<u32>,
+    pub recurrence: Option<RecurrenceRule>,
+    pub invite_code: Option<InviteCode>,
+    pub groups: Vec<ActionHash>,
+    pub slots: Vec<TimeSlot>,
+    pub encrypted_details: Option<EncryptedDetails>,
+    pub countersign_acceptances: bool,
+    pub require_check_in_token: bool,
+    pub closed: bool,
+}
+
+//invitees without a role of their own are required attendees
+pub const DEFAULT_INVITEE_ROLE: &str = "attendee";
+
+//the role of an invitee in the invitation (eg. speaker, attendee, volunteer) and whether their presence is required
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct InviteeRole {
+    pub agent: AgentPubKey,
+    pub role: String,
+    pub required: bool,
+}
+
+//a candidate time for an invitation that is polled before its start time is fixed
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TimeSlot {
+    pub start_time: Timestamp,
+    pub end_time: Option<Timestamp>,
+}
+
+//a shareable code for an open invitation, the author hands out the secret key of public_key and
+//whoever signs their own agent key with it can join the invitees
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct InviteCode {
+    pub public_key: AgentPubKey,
+    pub max_redemptions: Option<u32>,
+    pub expires_at: Option<Timestamp>,
 }
 
 //Input DTO from the UI
 #[derive(Clone, Debug, Serialize, Deserialize, SerializedBytes)]
 pub struct InviteInput {
     pub invitees: Vec<AgentPubKey>,
+    #[serde(default)]
+    pub invitee_roles: Vec<InviteeRole>,
+    #[serde(default)]
+    pub co_hosts: Vec<AgentPubKey>,
     pub location: Option<String>,
     pub start_time: Option<Timestamp>,
     pub end_time: Option<Timestamp>,
     pub details: Option<BTreeMap<String,String>>,
+    pub rsvp_deadline: Option<Timestamp>,
+    pub max_attendees: Option<u32>,
+    pub min_attendees: Option<u32>,
+    #[serde(default)]
+    pub allow_guests: bool,
+    pub max_guests_per_invitee: Option<u32>,
+    pub recurrence: Option<RecurrenceRule>,
+    pub invite_code: Option<InviteCode>,
+    #[serde(default)]
+    pub groups: Vec<ActionHash>,
+    #[serde(default)]
+    pub slots: Vec<TimeSlot>,
+    //left out on an update the details stay encrypted if the latest version is encrypted
+    pub encrypt_details: Option<bool>,
+    #[serde(default)]
+    pub countersign_acceptances: bool,
+    #[serde(default)]
+    pub require_check_in_token: bool,
     pub creation_hash: Option<ActionHash>
 }
 
@@ -31,14 +96,100 @@ pub struct InviteInfo {
     pub author: AgentPubKey,
     pub invitees_who_accepted: Vec<AgentPubKey>,
     pub invitees_who_rejected: Vec<AgentPubKey>,
+    pub invitees_who_are_tentative: Vec<AgentPubKey>,
+    pub invitees_waitlisted: Vec<AgentPubKey>,
     pub invitees_pending: Vec<AgentPubKey>,
+    pub invitees_redeemed: Vec<AgentPubKey>,
+    pub invitees_checked_in: Vec<AgentPubKey>,
+    pub invitees_delivered: Option<Vec<AgentPubKey>>,
+    pub invitee_roles: Vec<InviteeRole>,
+    pub slot_tallies: Vec<SlotTally>,
+    pub required_invitees_who_accepted: Vec<AgentPubKey>,
+    pub optional_invitees_who_accepted: Vec<AgentPubKey>,
+    pub required_invitees_who_rejected: Vec<AgentPubKey>,
+    pub optional_invitees_who_rejected: Vec<AgentPubKey>,
+    pub headcount: u32,
+    pub invitee_responses: Vec<InviteResponse>,
+    pub cancelled: bool,
+    pub expired: bool,
+    pub quorum_reached: bool,
+    pub did_not_happen: bool,
+}
+
+//Output DTO for a single response in the history of an invitation
+#[derive(Clone, Debug, Serialize, Deserialize, SerializedBytes)]
+pub struct InviteResponse {
+    pub invitee: AgentPubKey,
+    pub response: String,
+    pub guests: u32,
+    pub note: Option<String>,
+    pub reason: Option<ResponseReason>,
+    pub occurrence: Option<Timestamp>,
+    pub invite_action_hash: ActionHash,
+    pub timestamp: Timestamp,
+    pub response_hash: ActionHash,
+    pub countersigned: bool,
+}
+
+//Input DTO from the UI to accept an invitation, optionally bringing guests.
+//a recurring invitation is accepted for all occurrences unless one is given.
+//an invitation that countersigns its acceptances needs the Acceptance made with countersign_acceptance first
+#[derive(Clone, Debug, Serialize, Deserialize, SerializedBytes)]
+pub struct AcceptInput {
+    pub creation_hash: ActionHash,
+    #[serde(default)]
+    pub guests: u32,
+    pub note: Option<String>,
+    pub reason: Option<ResponseReason>,
+    pub occurrence: Option<Timestamp>,
+    pub acceptance_hash: Option<ActionHash>,
+}
+
+//Input DTO from the UI to reject an invitation, optionally saying why
+#[derive(Clone, Debug, Serialize, Deserialize, SerializedBytes)]
+pub struct RejectInput {
+    pub creation_hash: ActionHash,
+    pub note: Option<String>,
+    pub reason: Option<ResponseReason>,
+    pub occurrence: Option<Timestamp>,
+}
+
+//Input DTO from the UI to respond tentatively ("maybe"), optionally saying why
+#[derive(Clone, Debug, Serialize, Deserialize, SerializedBytes)]
+pub struct TentativeInput {
+    pub creation_hash: ActionHash,
+    pub note: Option<String>,
+    pub reason: Option<ResponseReason>,
+    pub occurrence: Option<Timestamp>,
 }
 
 pub fn validate_create_invite(
     _action: EntryCreationAction,
     _invite: Invite,
 ) -> ExternResult<ValidateCallbackResult> {
-    Ok(ValidateCallbackResult::Valid)
+    if let Some(error) = validate_recurrence_rule(&_invite) {
+        return Ok(ValidateCallbackResult::Invalid(error));
+    }
+    if _invite.encrypted_details.is_some() && (_invite.location.is_some() || _invite.details.is_some()) {
+        return Ok(ValidateCallbackResult::Invalid("the location and details of an encrypted invite cannot be public".into()));
+    }
+    if _invite.slots.iter().any(|slot| slot.end_time.is_some_and(|end_time| end_time < slot.start_time)) {
+        return Ok(ValidateCallbackResult::Invalid("a time slot cannot end before it starts".into()));
+    }
+    for (index, invitee_role) in _invite.invitee_roles.iter().enumerate() {
+        if !_invite.invitees.contains(&invitee_role.agent) {
+            return Ok(ValidateCallbackResult::Invalid("only invitees can be given a role".into()));
+        }
+        if _invite.invitee_roles[..index].iter().any(|other| other.agent == invitee_role.agent) {
+            return Ok(ValidateCallbackResult::Invalid("an invitee can only have one role".into()));
+        }
+    }
+    match (_invite.min_attendees, _invite.max_attendees) {
+        (Some(min_attendees), Some(max_attendees)) if min_attendees > max_attendees => Ok(
+            ValidateCallbackResult::Invalid("the minimum of attendees is more than the maximum".into())
+        ),
+        _ => Ok(ValidateCallbackResult::Valid),
+    }
 }
 pub fn validate_update_invite(
     _action: Update,
@@ -46,11 +197,27 @@ pub fn validate_update_invite(
     _original_action: EntryCreationAction,
     _original_invite: Invite,
 ) -> ExternResult<ValidateCallbackResult> {
-    if _original_action.author() == &_action.author 
+    //co-hosts of the version being updated may edit it, but only the author decides who the co-hosts are and closes it.
+    //a closed invitation is final, and so is the rsvp deadline as responses are validated against the one it was created with
+    if let Some(error) = validate_recurrence_rule(&_invite) {
+        return Ok(ValidateCallbackResult::Invalid(error));
+    }
+    let author = get_invite_creation_author(&_original_action)?;
+    if _original_invite.closed {
+        Ok(ValidateCallbackResult::Invalid("A closed invitation cannot be updated".into()))
+    } else if _invite.rsvp_deadline != _original_invite.rsvp_deadline {
+        Ok(ValidateCallbackResult::Invalid("The rsvp deadline of an invitation cannot be changed".into()))
+    } else if author == _action.author
     {
         Ok(ValidateCallbackResult::Valid)
+    } else if !_original_invite.co_hosts.contains(&_action.author) {
+        Ok(ValidateCallbackResult::Invalid("Only the author or a co-host of the invitation can make updates".into()))
+    } else if _invite.co_hosts != _original_invite.co_hosts {
+        Ok(ValidateCallbackResult::Invalid("Only the author of the invitation can change its co-hosts".into()))
+    } else if _invite.closed {
+        Ok(ValidateCallbackResult::Invalid("Only the author of the invitation can close it".into()))
     } else {
-        Ok(ValidateCallbackResult::Invalid("Only the author of the invitation can make updates".into()))
+        Ok(ValidateCallbackResult::Valid)
     }
 }
 pub fn validate_delete_invite(
@@ -58,5 +225,75 @@ pub fn validate_delete_invite(
     _original_action: EntryCreationAction,
     _original_invite: Invite,
 ) -> ExternResult<ValidateCallbackResult> {
-    Ok(ValidateCallbackResult::Valid)
+    if get_invite_creation_author(&_original_action)? == _action.author
+    {
+        Ok(ValidateCallbackResult::Valid)
+    } else {
+        Ok(ValidateCallbackResult::Invalid("Only the author of the invitation can cancel it".into()))
+    }
+}
+
+//invitees vote on the slots of an invitation until one of them is chosen as its start time
+pub fn is_slot_poll_open(invite: &Invite) -> bool {
+    !invite.slots.is_empty() && invite.start_time.is_none()
+}
+
+//the role given to the agent in the invitation, or the default one
+pub fn get_invitee_role(invite: &Invite, agent: &AgentPubKey) -> InviteeRole {
+    match invite.invitee_roles.iter().find(|invitee_role| &invitee_role.agent == agent) {
+        Some(invitee_role) => invitee_role.clone(),
+        None => InviteeRole {
+            agent: agent.clone(),
+            role: String::from(DEFAULT_INVITEE_ROLE),
+            required: true,
+        },
+    }
+}
+
+//walk back along the update chain to find who created the invitation in the first place
+pub fn get_invite_creation_author(invite_action: &EntryCreationAction) -> ExternResult<AgentPubKey> {
+    match invite_action {
+        EntryCreationAction::Create(create) => Ok(create.author.clone()),
+        EntryCreationAction::Update(update) => {
+            let mut previous_action_hash = update.original_action_address.clone();
+            loop {
+                let previous_action = must_get_action(previous_action_hash)?;
+                match previous_action.action() {
+                    Action::Create(create) => return Ok(create.author.clone()),
+                    Action::Update(update) => previous_action_hash = update.original_action_address.clone(),
+                    _ => return Err(wasm_error!(
+                        WasmErrorInner::Guest(String::from("Invite update chain must lead back to a Create action"))
+                    )),
+                }
+            }
+        }
+    }
+}
+
+//get the Invite at a given version, checking that the version belongs to the update chain of the creation action
+pub fn must_get_invite_version(create_action_hash: &ActionHash, invite_action_hash: &ActionHash) -> ExternResult<Invite> {
+    let record = must_get_valid_record(invite_action_hash.clone())?;
+    let invite: Invite = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+            ),
+        )?;
+    let mut action_hash = invite_action_hash.clone();
+    let mut action = record.signed_action.hashed.content;
+    while &action_hash != create_action_hash {
+        match action {
+            Action::Update(update) => {
+                action_hash = update.original_action_address;
+                action = must_get_action(action_hash.clone())?.hashed.content;
+            }
+            _ => return Err(wasm_error!(
+                WasmErrorInner::Guest(String::from("Invite version is not an update of the linked invitation"))
+            )),
+        }
+    }
+    Ok(invite)
 }
@@ -0,0 +1,72 @@
+use std::collections::BTreeMap;
+
+use hdi::prelude::*;
+
+//a private template to create near-identical invitations from, the duration is in microseconds
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct InviteTemplate {
+    pub name: String,
+    pub invitees: Vec<AgentPubKey>,
+    pub location: Option<String>,
+    pub duration: Option<i64>,
+    pub details: Option<BTreeMap<String,String>>,
+}
+
+//Input DTO from the UI to update a template, the template hash is the hash of its creation
+#[derive(Clone, Debug, Serialize, Deserialize, SerializedBytes)]
+pub struct UpdateInviteTemplateInput {
+    pub template_hash: ActionHash,
+    pub template: InviteTemplate,
+}
+
+//Input DTO from the UI to create an invitation that starts at the given time
+#[derive(Clone, Debug, Serialize, Deserialize, SerializedBytes)]
+pub struct InviteFromTemplateInput {
+    pub template_hash: ActionHash,
+    pub start_time: Timestamp,
+}
+
+//Output DTO for the UI
+#[derive(Clone, Debug, Serialize, Deserialize, SerializedBytes)]
+pub struct InviteTemplateInfo {
+    pub template: InviteTemplate,
+    pub template_hash: ActionHash,
+    pub timestamp: Timestamp,
+}
+
+pub fn validate_create_invite_template(
+    _action: EntryCreationAction,
+    _invite_template: InviteTemplate,
+) -> ExternResult<ValidateCallbackResult> {
+    if _invite_template.duration.is_some_and(|duration| duration < 0) {
+        Ok(ValidateCallbackResult::Invalid("the duration of a template cannot be negative".into()))
+    } else {
+        Ok(ValidateCallbackResult::Valid)
+    }
+}
+pub fn validate_update_invite_template(
+    _action: Update,
+    _invite_template: InviteTemplate,
+    _original_action: EntryCreationAction,
+    _original_invite_template: InviteTemplate,
+) -> ExternResult<ValidateCallbackResult> {
+    if _original_action.author() == &_action.author
+    {
+        Ok(ValidateCallbackResult::Valid)
+    } else {
+        Ok(ValidateCallbackResult::Invalid("Only the author of a template can update it".into()))
+    }
+}
+pub fn validate_delete_invite_template(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_invite_template: InviteTemplate,
+) -> ExternResult<ValidateCallbackResult> {
+    if _original_action.author() == &_action.author
+    {
+        Ok(ValidateCallbackResult::Valid)
+    } else {
+        Ok(ValidateCallbackResult::Invalid("Only the author of a template can delete it".into()))
+    }
+}
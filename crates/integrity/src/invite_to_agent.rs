@@ -1,5 +1,50 @@
 use hdi::prelude::*;
-use crate::Invite;
+use crate::{is_acceptance_of, is_occurrence, is_redemption_of, must_get_invite_version};
+
+//responses an invitee can give to an invitation
+pub const INVITE_RESPONSES: [&str; 3] = ["accepted", "rejected", "tentative"];
+
+//machine readable reason that can be given along with a response
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum ResponseReason {
+    Conflict,
+    Travel,
+    Sick,
+    Other,
+}
+
+//the response tag keeps track of the Invite version that was responded to,
+//so invitees added by an update can be validated against that version.
+//a response to a single occurrence of a recurring invitation records its original start time
+//and an agent that joined with an invite code refers to their redemption.
+//accepting an invite that countersigns its acceptances refers to the countersigned Acceptance
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, SerializedBytes)]
+pub struct InviteResponseTag {
+    pub response: String,
+    pub invite_action_hash: ActionHash,
+    pub guests: u32,
+    pub note: Option<String>,
+    pub reason: Option<ResponseReason>,
+    pub occurrence: Option<Timestamp>,
+    pub redemption_hash: Option<ActionHash>,
+    pub acceptance_hash: Option<ActionHash>,
+}
+
+impl TryFrom<InviteResponseTag> for LinkTag {
+    type Error = WasmError;
+    fn try_from(response_tag: InviteResponseTag) -> Result<Self, Self::Error> {
+        let bytes = SerializedBytes::try_from(response_tag).map_err(|e| wasm_error!(e))?;
+        Ok(LinkTag::new(bytes.bytes().clone()))
+    }
+}
+
+impl TryFrom<LinkTag> for InviteResponseTag {
+    type Error = WasmError;
+    fn try_from(tag: LinkTag) -> Result<Self, Self::Error> {
+        InviteResponseTag::try_from(SerializedBytes::from(UnsafeBytes::from(tag.into_inner())))
+            .map_err(|e| wasm_error!(e))
+    }
+}
 
 pub fn validate_create_link_invite_to_agent(
     _action: CreateLink,
@@ -7,23 +52,50 @@ pub fn validate_create_link_invite_to_agent(
     _target_address: AnyLinkableHash,
     _tag: LinkTag,
 ) -> ExternResult<ValidateCallbackResult> {
+    if _target_address != AnyLinkableHash::from(_action.author.clone()) {
+        return Ok(ValidateCallbackResult::Invalid("invitees can only respond for themselves".into()));
+    }
     let action_hash = base_address.into_action_hash().ok_or(
         wasm_error!(
             WasmErrorInner::Guest(String::from("base address is not a compatible link hash"))
         ),
     )?;
-    let record = must_get_valid_record(action_hash)?;
-    let _invite: Invite = record
-        .entry()
-        .to_app_option()
-        .map_err(|e| wasm_error!(e))?
-        .ok_or(
-            wasm_error!(
-                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
-            ),
-        )?;
-    if !_invite.invitees.contains(&_action.author) {
+    let response_tag = match InviteResponseTag::try_from(_tag) {
+        Ok(response_tag) => response_tag,
+        Err(_) => return Ok(ValidateCallbackResult::Invalid("response link tag is malformed".into())),
+    };
+    if !INVITE_RESPONSES.contains(&response_tag.response.as_str()) {
+        return Ok(ValidateCallbackResult::Invalid(format!("{} is not a valid response", response_tag.response)));
+    }
+    //the responder chooses the version in the tag and integrity cannot tell whether a later version exists,
+    //so a response citing a version from before the invite was closed passes here and is left out when reading the responses instead
+    let _invite = must_get_invite_version(&action_hash, &response_tag.invite_action_hash)?;
+    //the responder chooses the version, the rsvp deadline is read from the creation instead since updates cannot change it
+    let rsvp_deadline = must_get_invite_version(&action_hash, &action_hash)?.rsvp_deadline;
+    let is_invitee = _invite.invitees.contains(&_action.author) || match &response_tag.redemption_hash {
+        Some(redemption_hash) => is_redemption_of(&action_hash, redemption_hash, &_action.author)?,
+        None => false,
+    };
+    let is_countersigned = match &response_tag.acceptance_hash {
+        Some(acceptance_hash) => is_acceptance_of(&action_hash, acceptance_hash, &_action.author)?,
+        None => false,
+    };
+    if !is_invitee {
         Ok(ValidateCallbackResult::Invalid("only invitees can respond to invites".into()))
+    } else if _invite.countersign_acceptances && response_tag.response == "accepted" && !is_countersigned {
+        Ok(ValidateCallbackResult::Invalid("accepting the invite needs an acceptance countersigned with its author".into()))
+    } else if _invite.closed {
+        Ok(ValidateCallbackResult::Invalid("the invite is closed for responses".into()))
+    } else if rsvp_deadline.is_some_and(|rsvp_deadline| _action.timestamp > rsvp_deadline) {
+        Ok(ValidateCallbackResult::Invalid("the rsvp deadline of the invite has passed".into()))
+    } else if response_tag.guests > 0 && response_tag.response != "accepted" {
+        Ok(ValidateCallbackResult::Invalid("guests can only be brought when accepting an invite".into()))
+    } else if response_tag.guests > 0 && !_invite.allow_guests {
+        Ok(ValidateCallbackResult::Invalid("the invite does not allow guests".into()))
+    } else if _invite.max_guests_per_invitee.is_some_and(|max_guests| response_tag.guests > max_guests) {
+        Ok(ValidateCallbackResult::Invalid("too many guests for this invite".into()))
+    } else if response_tag.occurrence.is_some_and(|occurrence| !is_occurrence(&_invite, occurrence)) {
+        Ok(ValidateCallbackResult::Invalid("the invite does not recur at that time".into()))
     } else {
         Ok(ValidateCallbackResult::Valid)
     }
@@ -35,6 +107,6 @@ pub fn validate_delete_link_invite_to_agent(
     _target: AnyLinkableHash,
     _tag: LinkTag,
 ) -> ExternResult<ValidateCallbackResult> {
-    Ok(ValidateCallbackResult::Invalid("Deleting links is not allowed".into()))
+    Ok(ValidateCallbackResult::Invalid("Deleting links is not allowed, responses are kept as history".into()))
 
 }
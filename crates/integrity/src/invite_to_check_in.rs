@@ -0,0 +1,146 @@
+use hdi::prelude::*;
+use crate::{is_occurrence, is_redemption_of, must_get_invite_version, Invite};
+
+//what a host signs to hand out a check-in token, the token is only good for one invitee of one invitation
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, SerializedBytes)]
+pub struct CheckInTokenData {
+    pub creation_hash: ActionHash,
+    pub invitee: AgentPubKey,
+}
+
+//a token issued by a host of the invitation, eg. shown as a QR code at the door
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, SerializedBytes)]
+pub struct CheckInToken {
+    pub host: AgentPubKey,
+    pub signature: Signature,
+}
+
+//the check-in tag keeps the Invite version that was checked in to, the token of a host
+//and, for an agent that joined with an invite code, their redemption.
+//a check-in to a recurring invitation records the original start time of its occurrence
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, SerializedBytes)]
+pub struct CheckInTag {
+    pub invite_action_hash: ActionHash,
+    pub token: Option<CheckInToken>,
+    pub redemption_hash: Option<ActionHash>,
+    pub occurrence: Option<Timestamp>,
+}
+
+impl TryFrom<CheckInTag> for LinkTag {
+    type Error = WasmError;
+    fn try_from(check_in_tag: CheckInTag) -> Result<Self, Self::Error> {
+        let bytes = SerializedBytes::try_from(check_in_tag).map_err(|e| wasm_error!(e))?;
+        Ok(LinkTag::new(bytes.bytes().clone()))
+    }
+}
+
+impl TryFrom<LinkTag> for CheckInTag {
+    type Error = WasmError;
+    fn try_from(tag: LinkTag) -> Result<Self, Self::Error> {
+        CheckInTag::try_from(SerializedBytes::from(UnsafeBytes::from(tag.into_inner())))
+            .map_err(|e| wasm_error!(e))
+    }
+}
+
+//Input DTO from the UI to check in to an invitation, a recurring invitation is checked in to
+//the occurrence taking place unless one is given
+#[derive(Clone, Debug, Serialize, Deserialize, SerializedBytes)]
+pub struct CheckInInput {
+    pub creation_hash: ActionHash,
+    pub token: Option<CheckInToken>,
+    pub occurrence: Option<Timestamp>,
+}
+
+//Input DTO from the UI for a host to issue a check-in token to an invitee
+#[derive(Clone, Debug, Serialize, Deserialize, SerializedBytes)]
+pub struct IssueCheckInTokenInput {
+    pub creation_hash: ActionHash,
+    pub invitee: AgentPubKey,
+}
+
+//invitees check in between the start and end time of the invitation, without an end time there is no upper bound
+pub fn validate_create_link_invite_to_check_in(
+    _action: CreateLink,
+    base_address: AnyLinkableHash,
+    _target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if _target_address != AnyLinkableHash::from(_action.author.clone()) {
+        return Ok(ValidateCallbackResult::Invalid("invitees can only check in for themselves".into()));
+    }
+    let action_hash = base_address.into_action_hash().ok_or(
+        wasm_error!(
+            WasmErrorInner::Guest(String::from("base address is not a compatible link hash"))
+        ),
+    )?;
+    let check_in_tag = match CheckInTag::try_from(_tag) {
+        Ok(check_in_tag) => check_in_tag,
+        Err(_) => return Ok(ValidateCallbackResult::Invalid("check-in link tag is malformed".into())),
+    };
+    let _invite = must_get_invite_version(&action_hash, &check_in_tag.invite_action_hash)?;
+    let is_invitee = _invite.invitees.contains(&_action.author) || match &check_in_tag.redemption_hash {
+        Some(redemption_hash) => is_redemption_of(&action_hash, redemption_hash, &_action.author)?,
+        None => false,
+    };
+    if !is_invitee {
+        return Ok(ValidateCallbackResult::Invalid("only invitees can check in to invites".into()));
+    }
+    if _invite.start_time.is_none() {
+        return Ok(ValidateCallbackResult::Invalid("the invite has no start time to check in to".into()));
+    }
+    let (start_time, end_time) = match get_check_in_window(&_invite, check_in_tag.occurrence) {
+        Some(window) => window,
+        None => return Ok(ValidateCallbackResult::Invalid("the invite does not recur at that time".into())),
+    };
+    if _action.timestamp < start_time || end_time.is_some_and(|end_time| _action.timestamp > end_time) {
+        return Ok(ValidateCallbackResult::Invalid("check-in is only possible between the start and end time of the invite".into()));
+    }
+    match check_in_tag.token {
+        Some(token) => {
+            let author = must_get_action(action_hash.clone())?.hashed.content.author().clone();
+            let token_data = CheckInTokenData {
+                creation_hash: action_hash,
+                invitee: _action.author,
+            };
+            if token.host != author && !_invite.co_hosts.contains(&token.host) {
+                Ok(ValidateCallbackResult::Invalid("the check-in token was not issued by a host of the invite".into()))
+            } else if !verify_signature(token.host, token.signature, token_data)? {
+                Ok(ValidateCallbackResult::Invalid("the check-in token signature is not valid".into()))
+            } else {
+                Ok(ValidateCallbackResult::Valid)
+            }
+        }
+        None if _invite.require_check_in_token => {
+            Ok(ValidateCallbackResult::Invalid("the invite requires a check-in token".into()))
+        }
+        None => Ok(ValidateCallbackResult::Valid),
+    }
+}
+
+//the start and end time of the occurrence, an occurrence lasts as long as the first one.
+//None when the invite has no start time, does not recur at the given time or the end does not fit in a Timestamp
+pub fn get_check_in_window(invite: &Invite, occurrence: Option<Timestamp>) -> Option<(Timestamp, Option<Timestamp>)> {
+    let start_time = invite.start_time?;
+    let occurrence_start = match occurrence {
+        Some(occurrence) if is_occurrence(invite, occurrence) => occurrence,
+        Some(_) => return None,
+        None => start_time,
+    };
+    let end_time = match invite.end_time {
+        Some(end_time) => Some(Timestamp::from_micros(
+            occurrence_start.as_micros().checked_add(end_time.as_micros().checked_sub(start_time.as_micros())?)?
+        )),
+        None => None,
+    };
+    Some((occurrence_start, end_time))
+}
+
+pub fn validate_delete_link_invite_to_check_in(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid("Check-ins cannot be deleted".into()))
+}
@@ -0,0 +1,41 @@
+use hdi::prelude::*;
+use crate::OccurrenceException;
+
+pub fn validate_create_link_invite_to_exception(
+    _action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let action_hash = target_address.into_action_hash().ok_or(
+        wasm_error!(
+            WasmErrorInner::Guest(String::from("target address is not a compatible link hash"))
+        ),
+    )?;
+    let record = must_get_valid_record(action_hash)?;
+    let occurrence_exception: OccurrenceException = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+            ),
+        )?;
+    if base_address != AnyLinkableHash::from(occurrence_exception.creation_hash) {
+        Ok(ValidateCallbackResult::Invalid("an occurrence exception can only be linked from its invitation".into()))
+    } else if record.action().author() != &_action.author {
+        Ok(ValidateCallbackResult::Invalid("only the author of an occurrence exception can link it".into()))
+    } else {
+        Ok(ValidateCallbackResult::Valid)
+    }
+}
+pub fn validate_delete_link_invite_to_exception(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid("Occurrence exceptions cannot be unlinked".into()))
+}
@@ -0,0 +1,45 @@
+use hdi::prelude::*;
+use crate::TimeProposal;
+
+pub fn validate_create_link_invite_to_proposal(
+    _action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let action_hash = target_address.into_action_hash().ok_or(
+        wasm_error!(
+            WasmErrorInner::Guest(String::from("target address is not a compatible link hash"))
+        ),
+    )?;
+    let record = must_get_valid_record(action_hash)?;
+    let time_proposal: TimeProposal = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+            ),
+        )?;
+    if base_address != AnyLinkableHash::from(time_proposal.creation_hash) {
+        Ok(ValidateCallbackResult::Invalid("a time proposal can only be linked from its invitation".into()))
+    } else if record.action().author() != &_action.author {
+        Ok(ValidateCallbackResult::Invalid("only the author of a time proposal can link it".into()))
+    } else {
+        Ok(ValidateCallbackResult::Valid)
+    }
+}
+pub fn validate_delete_link_invite_to_proposal(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if _original_action.author == _action.author {
+        Ok(ValidateCallbackResult::Valid)
+    } else {
+        Ok(ValidateCallbackResult::Invalid("Only the author of a time proposal can unlink it".into()))
+    }
+}
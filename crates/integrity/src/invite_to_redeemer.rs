@@ -0,0 +1,89 @@
+use hdi::prelude::*;
+use crate::{must_get_invite_version, LinkTypes};
+
+//the redemption tag holds the signature over the redeemer's own agent key made with the secret key of the invite code,
+//and the Invite version that had the code
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, SerializedBytes)]
+pub struct RedemptionTag {
+    pub invite_action_hash: ActionHash,
+    pub signature: Signature,
+}
+
+impl TryFrom<RedemptionTag> for LinkTag {
+    type Error = WasmError;
+    fn try_from(redemption_tag: RedemptionTag) -> Result<Self, Self::Error> {
+        let bytes = SerializedBytes::try_from(redemption_tag).map_err(|e| wasm_error!(e))?;
+        Ok(LinkTag::new(bytes.bytes().clone()))
+    }
+}
+
+impl TryFrom<LinkTag> for RedemptionTag {
+    type Error = WasmError;
+    fn try_from(tag: LinkTag) -> Result<Self, Self::Error> {
+        RedemptionTag::try_from(SerializedBytes::from(UnsafeBytes::from(tag.into_inner())))
+            .map_err(|e| wasm_error!(e))
+    }
+}
+
+//Input DTO from the UI to join an open invitation
+#[derive(Clone, Debug, Serialize, Deserialize, SerializedBytes)]
+pub struct RedeemInput {
+    pub creation_hash: ActionHash,
+    pub signature: Signature,
+}
+
+//the number of redemptions cannot be validated here, only the first max_redemptions redeemers are counted as invitees.
+//the redeemer chooses the version in the tag, so redemptions are checked against the current code again when reading them
+pub fn validate_create_link_invite_to_redeemer(
+    _action: CreateLink,
+    base_address: AnyLinkableHash,
+    _target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if _target_address != AnyLinkableHash::from(_action.author.clone()) {
+        return Ok(ValidateCallbackResult::Invalid("invite codes can only be redeemed for yourself".into()));
+    }
+    let action_hash = base_address.into_action_hash().ok_or(
+        wasm_error!(
+            WasmErrorInner::Guest(String::from("base address is not a compatible link hash"))
+        ),
+    )?;
+    let redemption_tag = match RedemptionTag::try_from(_tag) {
+        Ok(redemption_tag) => redemption_tag,
+        Err(_) => return Ok(ValidateCallbackResult::Invalid("redemption link tag is malformed".into())),
+    };
+    let _invite = must_get_invite_version(&action_hash, &redemption_tag.invite_action_hash)?;
+    let invite_code = match _invite.invite_code {
+        Some(invite_code) => invite_code,
+        None => return Ok(ValidateCallbackResult::Invalid("the invite has no invite code".into())),
+    };
+    if invite_code.expires_at.is_some_and(|expires_at| _action.timestamp > expires_at) {
+        Ok(ValidateCallbackResult::Invalid("the invite code has expired".into()))
+    } else if !verify_signature_raw(invite_code.public_key, redemption_tag.signature, _action.author.get_raw_39().to_vec())? {
+        Ok(ValidateCallbackResult::Invalid("the invite code signature is not valid".into()))
+    } else {
+        Ok(ValidateCallbackResult::Valid)
+    }
+}
+pub fn validate_delete_link_invite_to_redeemer(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid("Redemptions cannot be deleted".into()))
+}
+
+//a valid redemption of the invitation by the agent lets them respond like an invitee
+pub fn is_redemption_of(create_action_hash: &ActionHash, redemption_hash: &ActionHash, agent: &AgentPubKey) -> ExternResult<bool> {
+    let record = must_get_valid_record(redemption_hash.clone())?;
+    match record.action() {
+        Action::CreateLink(create_link) => Ok(
+            &create_link.author == agent
+                && create_link.base_address == AnyLinkableHash::from(create_action_hash.clone())
+                && LinkTypes::from_type(create_link.zome_index, create_link.link_type)? == Some(LinkTypes::InviteToRedeemer)
+        ),
+        _ => Ok(false),
+    }
+}
@@ -0,0 +1,95 @@
+use hdi::prelude::*;
+use crate::{is_slot_poll_open, must_get_invite_version, TimeSlot};
+
+//votes an invitee can give to a time slot
+pub const SLOT_VOTES: [&str; 3] = ["yes", "maybe", "no"];
+
+//the vote tag keeps the Invite version that was voted on, a later vote on the same slot replaces an earlier one
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, SerializedBytes)]
+pub struct SlotVoteTag {
+    pub invite_action_hash: ActionHash,
+    pub slot: TimeSlot,
+    pub vote: String,
+}
+
+impl TryFrom<SlotVoteTag> for LinkTag {
+    type Error = WasmError;
+    fn try_from(vote_tag: SlotVoteTag) -> Result<Self, Self::Error> {
+        let bytes = SerializedBytes::try_from(vote_tag).map_err(|e| wasm_error!(e))?;
+        Ok(LinkTag::new(bytes.bytes().clone()))
+    }
+}
+
+impl TryFrom<LinkTag> for SlotVoteTag {
+    type Error = WasmError;
+    fn try_from(tag: LinkTag) -> Result<Self, Self::Error> {
+        SlotVoteTag::try_from(SerializedBytes::from(UnsafeBytes::from(tag.into_inner())))
+            .map_err(|e| wasm_error!(e))
+    }
+}
+
+//Input DTO from the UI to vote on a slot
+#[derive(Clone, Debug, Serialize, Deserialize, SerializedBytes)]
+pub struct SlotVoteInput {
+    pub creation_hash: ActionHash,
+    pub slot: TimeSlot,
+    pub vote: String,
+}
+
+//Input DTO from the UI to make a slot the time of the invitation
+#[derive(Clone, Debug, Serialize, Deserialize, SerializedBytes)]
+pub struct FinalizeSlotInput {
+    pub creation_hash: ActionHash,
+    pub slot: TimeSlot,
+}
+
+//Output DTO with the invitees that voted yes, maybe or no on a slot
+#[derive(Clone, Debug, Serialize, Deserialize, SerializedBytes)]
+pub struct SlotTally {
+    pub slot: TimeSlot,
+    pub yes: Vec<AgentPubKey>,
+    pub maybe: Vec<AgentPubKey>,
+    pub no: Vec<AgentPubKey>,
+}
+
+pub fn validate_create_link_invite_to_slot_vote(
+    _action: CreateLink,
+    base_address: AnyLinkableHash,
+    _target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if _target_address != AnyLinkableHash::from(_action.author.clone()) {
+        return Ok(ValidateCallbackResult::Invalid("invitees can only vote for themselves".into()));
+    }
+    let action_hash = base_address.into_action_hash().ok_or(
+        wasm_error!(
+            WasmErrorInner::Guest(String::from("base address is not a compatible link hash"))
+        ),
+    )?;
+    let vote_tag = match SlotVoteTag::try_from(_tag) {
+        Ok(vote_tag) => vote_tag,
+        Err(_) => return Ok(ValidateCallbackResult::Invalid("slot vote link tag is malformed".into())),
+    };
+    if !SLOT_VOTES.contains(&vote_tag.vote.as_str()) {
+        return Ok(ValidateCallbackResult::Invalid(format!("{} is not a valid vote", vote_tag.vote)));
+    }
+    let _invite = must_get_invite_version(&action_hash, &vote_tag.invite_action_hash)?;
+    if !_invite.invitees.contains(&_action.author) {
+        Ok(ValidateCallbackResult::Invalid("only invitees can vote on the slots of an invite".into()))
+    } else if _invite.closed || !is_slot_poll_open(&_invite) {
+        Ok(ValidateCallbackResult::Invalid("the invite is not polling its time".into()))
+    } else if !_invite.slots.contains(&vote_tag.slot) {
+        Ok(ValidateCallbackResult::Invalid("the invite has no such slot".into()))
+    } else {
+        Ok(ValidateCallbackResult::Valid)
+    }
+}
+pub fn validate_delete_link_invite_to_slot_vote(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid("Deleting links is not allowed, votes are kept as history".into()))
+}
@@ -4,19 +4,81 @@ pub mod agent_to_invite;
 pub use agent_to_invite::*;
 pub mod invite;
 pub use invite::*;
+pub mod time_proposal;
+pub use time_proposal::*;
+pub mod invite_to_proposal;
+pub use invite_to_proposal::*;
+pub mod recurrence;
+pub use recurrence::*;
+pub mod occurrence_exception;
+pub use occurrence_exception::*;
+pub mod invite_to_exception;
+pub use invite_to_exception::*;
+pub mod invite_template;
+pub use invite_template::*;
+pub mod invite_to_redeemer;
+pub use invite_to_redeemer::*;
+pub mod group;
+pub use group::*;
+pub mod group_to_invite;
+pub use group_to_invite::*;
+pub mod invite_to_slot_vote;
+pub use invite_to_slot_vote::*;
+pub mod encryption_key;
+pub use encryption_key::*;
+pub mod agent_to_encryption_key;
+pub use agent_to_encryption_key::*;
+pub mod acceptance;
+pub use acceptance::*;
+pub mod invite_to_check_in;
+pub use invite_to_check_in::*;
+pub mod reminder_settings;
+pub use reminder_settings::*;
+pub mod fired_reminder;
+pub use fired_reminder::*;
+pub mod agent_to_notification;
+pub use agent_to_notification::*;
+pub mod notification_read;
+pub use notification_read::*;
+pub mod invitation_notice;
+pub use invitation_notice::*;
 use hdi::prelude::*;
 #[derive(Serialize, Deserialize)]
 #[serde(tag = "type")]
+#[allow(clippy::large_enum_variant)]
 #[hdk_entry_defs]
 #[unit_enum(UnitEntryTypes)]
 pub enum EntryTypes {
     Invite(Invite),
+    TimeProposal(TimeProposal),
+    OccurrenceException(OccurrenceException),
+    #[entry_def(visibility = "private")]
+    InviteTemplate(InviteTemplate),
+    Group(Group),
+    EncryptionKey(EncryptionKey),
+    Acceptance(Acceptance),
+    #[entry_def(visibility = "private")]
+    ReminderSettings(ReminderSettings),
+    #[entry_def(visibility = "private")]
+    FiredReminder(FiredReminder),
+    #[entry_def(visibility = "private")]
+    NotificationRead(NotificationRead),
+    #[entry_def(visibility = "private")]
+    InvitationNotice(InvitationNotice),
 }
 #[derive(Serialize, Deserialize)]
 #[hdk_link_types]
 pub enum LinkTypes {
     AgentToInvite,
     InviteToAgent,
+    InviteToProposal,
+    InviteToException,
+    InviteToRedeemer,
+    GroupToInvite,
+    InviteToSlotVote,
+    AgentToEncryptionKey,
+    InviteToCheckIn,
+    AgentToNotification,
 }
 #[hdk_extern]
 pub fn genesis_self_check(
@@ -32,6 +94,9 @@ pub fn validate_agent_joining(
 }
 #[hdk_extern]
 pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
+    if let Some(invalid) = validate_acceptance_countersignatures(&op)? {
+        return Ok(invalid);
+    }
     match op.flattened::<EntryTypes, LinkTypes>()? {
         FlatOp::StoreEntry(store_entry) => {
             match store_entry {
@@ -43,6 +108,66 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                                 invite,
                             )
                         }
+                        EntryTypes::TimeProposal(time_proposal) => {
+                            validate_create_time_proposal(
+                                EntryCreationAction::Create(action),
+                                time_proposal,
+                            )
+                        }
+                        EntryTypes::OccurrenceException(occurrence_exception) => {
+                            validate_create_occurrence_exception(
+                                EntryCreationAction::Create(action),
+                                occurrence_exception,
+                            )
+                        }
+                        EntryTypes::InviteTemplate(invite_template) => {
+                            validate_create_invite_template(
+                                EntryCreationAction::Create(action),
+                                invite_template,
+                            )
+                        }
+                        EntryTypes::Group(group) => {
+                            validate_create_group(
+                                EntryCreationAction::Create(action),
+                                group,
+                            )
+                        }
+                        EntryTypes::EncryptionKey(encryption_key) => {
+                            validate_create_encryption_key(
+                                EntryCreationAction::Create(action),
+                                encryption_key,
+                            )
+                        }
+                        EntryTypes::Acceptance(acceptance) => {
+                            validate_create_acceptance(
+                                EntryCreationAction::Create(action),
+                                acceptance,
+                            )
+                        }
+                        EntryTypes::ReminderSettings(reminder_settings) => {
+                            validate_create_reminder_settings(
+                                EntryCreationAction::Create(action),
+                                reminder_settings,
+                            )
+                        }
+                        EntryTypes::FiredReminder(fired_reminder) => {
+                            validate_create_fired_reminder(
+                                EntryCreationAction::Create(action),
+                                fired_reminder,
+                            )
+                        }
+                        EntryTypes::NotificationRead(notification_read) => {
+                            validate_create_notification_read(
+                                EntryCreationAction::Create(action),
+                                notification_read,
+                            )
+                        }
+                        EntryTypes::InvitationNotice(invitation_notice) => {
+                            validate_create_invitation_notice(
+                                EntryCreationAction::Create(action),
+                                invitation_notice,
+                            )
+                        }
                     }
                 }
                 OpEntry::UpdateEntry { app_entry, action, .. } => {
@@ -53,6 +178,66 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                                 invite,
                             )
                         }
+                        EntryTypes::TimeProposal(time_proposal) => {
+                            validate_create_time_proposal(
+                                EntryCreationAction::Update(action),
+                                time_proposal,
+                            )
+                        }
+                        EntryTypes::OccurrenceException(occurrence_exception) => {
+                            validate_create_occurrence_exception(
+                                EntryCreationAction::Update(action),
+                                occurrence_exception,
+                            )
+                        }
+                        EntryTypes::InviteTemplate(invite_template) => {
+                            validate_create_invite_template(
+                                EntryCreationAction::Update(action),
+                                invite_template,
+                            )
+                        }
+                        EntryTypes::Group(group) => {
+                            validate_create_group(
+                                EntryCreationAction::Update(action),
+                                group,
+                            )
+                        }
+                        EntryTypes::EncryptionKey(encryption_key) => {
+                            validate_create_encryption_key(
+                                EntryCreationAction::Update(action),
+                                encryption_key,
+                            )
+                        }
+                        EntryTypes::Acceptance(acceptance) => {
+                            validate_create_acceptance(
+                                EntryCreationAction::Update(action),
+                                acceptance,
+                            )
+                        }
+                        EntryTypes::ReminderSettings(reminder_settings) => {
+                            validate_create_reminder_settings(
+                                EntryCreationAction::Update(action),
+                                reminder_settings,
+                            )
+                        }
+                        EntryTypes::FiredReminder(fired_reminder) => {
+                            validate_create_fired_reminder(
+                                EntryCreationAction::Update(action),
+                                fired_reminder,
+                            )
+                        }
+                        EntryTypes::NotificationRead(notification_read) => {
+                            validate_create_notification_read(
+                                EntryCreationAction::Update(action),
+                                notification_read,
+                            )
+                        }
+                        EntryTypes::InvitationNotice(invitation_notice) => {
+                            validate_create_invitation_notice(
+                                EntryCreationAction::Update(action),
+                                invitation_notice,
+                            )
+                        }
                     }
                 }
                 _ => Ok(ValidateCallbackResult::Valid),
@@ -78,6 +263,116 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                                 original_invite,
                             )
                         }
+                        (
+                            EntryTypes::TimeProposal(time_proposal),
+                            EntryTypes::TimeProposal(original_time_proposal),
+                        ) => {
+                            validate_update_time_proposal(
+                                action,
+                                time_proposal,
+                                original_action,
+                                original_time_proposal,
+                            )
+                        }
+                        (
+                            EntryTypes::OccurrenceException(occurrence_exception),
+                            EntryTypes::OccurrenceException(original_occurrence_exception),
+                        ) => {
+                            validate_update_occurrence_exception(
+                                action,
+                                occurrence_exception,
+                                original_action,
+                                original_occurrence_exception,
+                            )
+                        }
+                        (
+                            EntryTypes::InviteTemplate(invite_template),
+                            EntryTypes::InviteTemplate(original_invite_template),
+                        ) => {
+                            validate_update_invite_template(
+                                action,
+                                invite_template,
+                                original_action,
+                                original_invite_template,
+                            )
+                        }
+                        (
+                            EntryTypes::Group(group),
+                            EntryTypes::Group(original_group),
+                        ) => {
+                            validate_update_group(
+                                action,
+                                group,
+                                original_action,
+                                original_group,
+                            )
+                        }
+                        (
+                            EntryTypes::EncryptionKey(encryption_key),
+                            EntryTypes::EncryptionKey(original_encryption_key),
+                        ) => {
+                            validate_update_encryption_key(
+                                action,
+                                encryption_key,
+                                original_action,
+                                original_encryption_key,
+                            )
+                        }
+                        (
+                            EntryTypes::Acceptance(acceptance),
+                            EntryTypes::Acceptance(original_acceptance),
+                        ) => {
+                            validate_update_acceptance(
+                                action,
+                                acceptance,
+                                original_action,
+                                original_acceptance,
+                            )
+                        }
+                        (
+                            EntryTypes::ReminderSettings(reminder_settings),
+                            EntryTypes::ReminderSettings(original_reminder_settings),
+                        ) => {
+                            validate_update_reminder_settings(
+                                action,
+                                reminder_settings,
+                                original_action,
+                                original_reminder_settings,
+                            )
+                        }
+                        (
+                            EntryTypes::FiredReminder(fired_reminder),
+                            EntryTypes::FiredReminder(original_fired_reminder),
+                        ) => {
+                            validate_update_fired_reminder(
+                                action,
+                                fired_reminder,
+                                original_action,
+                                original_fired_reminder,
+                            )
+                        }
+                        (
+                            EntryTypes::NotificationRead(notification_read),
+                            EntryTypes::NotificationRead(original_notification_read),
+                        ) => {
+                            validate_update_notification_read(
+                                action,
+                                notification_read,
+                                original_action,
+                                original_notification_read,
+                            )
+                        }
+                        (
+                            EntryTypes::InvitationNotice(invitation_notice),
+                            EntryTypes::InvitationNotice(original_invitation_notice),
+                        ) => {
+                            validate_update_invitation_notice(
+                                action,
+                                invitation_notice,
+                                original_action,
+                                original_invitation_notice,
+                            )
+                        }
                         _ => {
                             Ok(
                                 ValidateCallbackResult::Invalid(
@@ -98,6 +393,36 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                         EntryTypes::Invite(invite) => {
                             validate_delete_invite(action, original_action, invite)
                         }
+                        EntryTypes::TimeProposal(time_proposal) => {
+                            validate_delete_time_proposal(action, original_action, time_proposal)
+                        }
+                        EntryTypes::OccurrenceException(occurrence_exception) => {
+                            validate_delete_occurrence_exception(action, original_action, occurrence_exception)
+                        }
+                        EntryTypes::InviteTemplate(invite_template) => {
+                            validate_delete_invite_template(action, original_action, invite_template)
+                        }
+                        EntryTypes::Group(group) => {
+                            validate_delete_group(action, original_action, group)
+                        }
+                        EntryTypes::EncryptionKey(encryption_key) => {
+                            validate_delete_encryption_key(action, original_action, encryption_key)
+                        }
+                        EntryTypes::Acceptance(acceptance) => {
+                            validate_delete_acceptance(action, original_action, acceptance)
+                        }
+                        EntryTypes::ReminderSettings(reminder_settings) => {
+                            validate_delete_reminder_settings(action, original_action, reminder_settings)
+                        }
+                        EntryTypes::FiredReminder(fired_reminder) => {
+                            validate_delete_fired_reminder(action, original_action, fired_reminder)
+                        }
+                        EntryTypes::NotificationRead(notification_read) => {
+                            validate_delete_notification_read(action, original_action, notification_read)
+                        }
+                        EntryTypes::InvitationNotice(invitation_notice) => {
+                            validate_delete_invitation_notice(action, original_action, invitation_notice)
+                        }
                     }
                 }
                 _ => Ok(ValidateCallbackResult::Valid),
@@ -127,6 +452,70 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                         tag,
                     )
                 }
+                LinkTypes::InviteToProposal => {
+                    validate_create_link_invite_to_proposal(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::InviteToException => {
+                    validate_create_link_invite_to_exception(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::InviteToRedeemer => {
+                    validate_create_link_invite_to_redeemer(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::GroupToInvite => {
+                    validate_create_link_group_to_invite(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::InviteToSlotVote => {
+                    validate_create_link_invite_to_slot_vote(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::AgentToEncryptionKey => {
+                    validate_create_link_agent_to_encryption_key(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::InviteToCheckIn => {
+                    validate_create_link_invite_to_check_in(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::AgentToNotification => {
+                    validate_create_link_agent_to_notification(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
             }
         }
         FlatOp::RegisterDeleteLink {
@@ -156,6 +545,78 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                         tag,
                     )
                 }
+                LinkTypes::InviteToProposal => {
+                    validate_delete_link_invite_to_proposal(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::InviteToException => {
+                    validate_delete_link_invite_to_exception(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::InviteToRedeemer => {
+                    validate_delete_link_invite_to_redeemer(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::GroupToInvite => {
+                    validate_delete_link_group_to_invite(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::InviteToSlotVote => {
+                    validate_delete_link_invite_to_slot_vote(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::AgentToEncryptionKey => {
+                    validate_delete_link_agent_to_encryption_key(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::InviteToCheckIn => {
+                    validate_delete_link_invite_to_check_in(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::AgentToNotification => {
+                    validate_delete_link_agent_to_notification(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
             }
         }
         FlatOp::StoreRecord(store_record) => {
@@ -168,41 +629,349 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                                 invite,
                             )
                         }
-                    }
-                }
-                OpRecord::UpdateEntry {
-                    original_action_hash,
-                    app_entry,
-                    action,
-                    ..
-                } => {
-                    let original_record = must_get_valid_record(original_action_hash)?;
-                    let original_action = original_record.action().clone();
-                    let original_action = match original_action {
-                        Action::Create(create) => EntryCreationAction::Create(create),
-                        Action::Update(update) => EntryCreationAction::Update(update),
-                        _ => {
-                            return Ok(
-                                ValidateCallbackResult::Invalid(
-                                    "Original action for an update must be a Create or Update action"
-                                        .to_string(),
-                                ),
-                            );
+                        EntryTypes::TimeProposal(time_proposal) => {
+                            validate_create_time_proposal(
+                                EntryCreationAction::Create(action),
+                                time_proposal,
+                            )
+                        }
+                        EntryTypes::OccurrenceException(occurrence_exception) => {
+                            validate_create_occurrence_exception(
+                                EntryCreationAction::Create(action),
+                                occurrence_exception,
+                            )
+                        }
+                        EntryTypes::InviteTemplate(invite_template) => {
+                            validate_create_invite_template(
+                                EntryCreationAction::Create(action),
+                                invite_template,
+                            )
+                        }
+                        EntryTypes::Group(group) => {
+                            validate_create_group(
+                                EntryCreationAction::Create(action),
+                                group,
+                            )
+                        }
+                        EntryTypes::EncryptionKey(encryption_key) => {
+                            validate_create_encryption_key(
+                                EntryCreationAction::Create(action),
+                                encryption_key,
+                            )
+                        }
+                        EntryTypes::Acceptance(acceptance) => {
+                            validate_create_acceptance(
+                                EntryCreationAction::Create(action),
+                                acceptance,
+                            )
+                        }
+                        EntryTypes::ReminderSettings(reminder_settings) => {
+                            validate_create_reminder_settings(
+                                EntryCreationAction::Create(action),
+                                reminder_settings,
+                            )
+                        }
+                        EntryTypes::FiredReminder(fired_reminder) => {
+                            validate_create_fired_reminder(
+                                EntryCreationAction::Create(action),
+                                fired_reminder,
+                            )
+                        }
+                        EntryTypes::NotificationRead(notification_read) => {
+                            validate_create_notification_read(
+                                EntryCreationAction::Create(action),
+                                notification_read,
+                            )
+                        }
+                        EntryTypes::InvitationNotice(invitation_notice) => {
+                            validate_create_invitation_notice(
+                                EntryCreationAction::Create(action),
+                                invitation_notice,
+                            )
+                        }
+                    }
+                }
+                OpRecord::UpdateEntry {
+                    original_action_hash,
+                    app_entry,
+                    action,
+                    ..
+                } => {
+                    let original_record = must_get_valid_record(original_action_hash)?;
+                    let original_action = original_record.action().clone();
+                    let original_action = match original_action {
+                        Action::Create(create) => EntryCreationAction::Create(create),
+                        Action::Update(update) => EntryCreationAction::Update(update),
+                        _ => {
+                            return Ok(
+                                ValidateCallbackResult::Invalid(
+                                    "Original action for an update must be a Create or Update action"
+                                        .to_string(),
+                                ),
+                            );
+                        }
+                    };
+                    match app_entry {
+                        EntryTypes::Invite(invite) => {
+                            let result = validate_create_invite(
+                                EntryCreationAction::Update(action.clone()),
+                                invite.clone(),
+                            )?;
+                            if let ValidateCallbackResult::Valid = result {
+                                let original_invite: Option<Invite> = original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                                let original_invite = match original_invite {
+                                    Some(invite) => invite,
+                                    None => {
+                                        return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                "The updated entry type must be the same as the original entry type"
+                                                    .to_string(),
+                                            ),
+                                        );
+                                    }
+                                };
+                                validate_update_invite(
+                                    action,
+                                    invite,
+                                    original_action,
+                                    original_invite,
+                                )
+                            } else {
+                                Ok(result)
+                            }
+                        }
+                        EntryTypes::TimeProposal(time_proposal) => {
+                            let result = validate_create_time_proposal(
+                                EntryCreationAction::Update(action.clone()),
+                                time_proposal.clone(),
+                            )?;
+                            if let ValidateCallbackResult::Valid = result {
+                                let original_time_proposal: Option<TimeProposal> = original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                                let original_time_proposal = match original_time_proposal {
+                                    Some(time_proposal) => time_proposal,
+                                    None => {
+                                        return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                "The updated entry type must be the same as the original entry type"
+                                                    .to_string(),
+                                            ),
+                                        );
+                                    }
+                                };
+                                validate_update_time_proposal(
+                                    action,
+                                    time_proposal,
+                                    original_action,
+                                    original_time_proposal,
+                                )
+                            } else {
+                                Ok(result)
+                            }
+                        }
+                        EntryTypes::OccurrenceException(occurrence_exception) => {
+                            let result = validate_create_occurrence_exception(
+                                EntryCreationAction::Update(action.clone()),
+                                occurrence_exception.clone(),
+                            )?;
+                            if let ValidateCallbackResult::Valid = result {
+                                let original_occurrence_exception: Option<OccurrenceException> = original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                                let original_occurrence_exception = match original_occurrence_exception {
+                                    Some(occurrence_exception) => occurrence_exception,
+                                    None => {
+                                        return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                "The updated entry type must be the same as the original entry type"
+                                                    .to_string(),
+                                            ),
+                                        );
+                                    }
+                                };
+                                validate_update_occurrence_exception(
+                                    action,
+                                    occurrence_exception,
+                                    original_action,
+                                    original_occurrence_exception,
+                                )
+                            } else {
+                                Ok(result)
+                            }
+                        }
+                        EntryTypes::InviteTemplate(invite_template) => {
+                            let result = validate_create_invite_template(
+                                EntryCreationAction::Update(action.clone()),
+                                invite_template.clone(),
+                            )?;
+                            if let ValidateCallbackResult::Valid = result {
+                                let original_invite_template: Option<InviteTemplate> = original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                                let original_invite_template = match original_invite_template {
+                                    Some(invite_template) => invite_template,
+                                    None => {
+                                        return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                "The updated entry type must be the same as the original entry type"
+                                                    .to_string(),
+                                            ),
+                                        );
+                                    }
+                                };
+                                validate_update_invite_template(
+                                    action,
+                                    invite_template,
+                                    original_action,
+                                    original_invite_template,
+                                )
+                            } else {
+                                Ok(result)
+                            }
+                        }
+                        EntryTypes::Group(group) => {
+                            let result = validate_create_group(
+                                EntryCreationAction::Update(action.clone()),
+                                group.clone(),
+                            )?;
+                            if let ValidateCallbackResult::Valid = result {
+                                let original_group: Option<Group> = original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                                let original_group = match original_group {
+                                    Some(group) => group,
+                                    None => {
+                                        return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                "The updated entry type must be the same as the original entry type"
+                                                    .to_string(),
+                                            ),
+                                        );
+                                    }
+                                };
+                                validate_update_group(
+                                    action,
+                                    group,
+                                    original_action,
+                                    original_group,
+                                )
+                            } else {
+                                Ok(result)
+                            }
+                        }
+                        EntryTypes::EncryptionKey(encryption_key) => {
+                            let result = validate_create_encryption_key(
+                                EntryCreationAction::Update(action.clone()),
+                                encryption_key.clone(),
+                            )?;
+                            if let ValidateCallbackResult::Valid = result {
+                                let original_encryption_key: Option<EncryptionKey> = original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                                let original_encryption_key = match original_encryption_key {
+                                    Some(encryption_key) => encryption_key,
+                                    None => {
+                                        return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                "The updated entry type must be the same as the original entry type"
+                                                    .to_string(),
+                                            ),
+                                        );
+                                    }
+                                };
+                                validate_update_encryption_key(
+                                    action,
+                                    encryption_key,
+                                    original_action,
+                                    original_encryption_key,
+                                )
+                            } else {
+                                Ok(result)
+                            }
+                        }
+                        EntryTypes::Acceptance(acceptance) => {
+                            let result = validate_create_acceptance(
+                                EntryCreationAction::Update(action.clone()),
+                                acceptance.clone(),
+                            )?;
+                            if let ValidateCallbackResult::Valid = result {
+                                let original_acceptance: Option<Acceptance> = original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                                let original_acceptance = match original_acceptance {
+                                    Some(acceptance) => acceptance,
+                                    None => {
+                                        return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                "The updated entry type must be the same as the original entry type"
+                                                    .to_string(),
+                                            ),
+                                        );
+                                    }
+                                };
+                                validate_update_acceptance(
+                                    action,
+                                    acceptance,
+                                    original_action,
+                                    original_acceptance,
+                                )
+                            } else {
+                                Ok(result)
+                            }
+                        }
+                        EntryTypes::ReminderSettings(reminder_settings) => {
+                            let result = validate_create_reminder_settings(
+                                EntryCreationAction::Update(action.clone()),
+                                reminder_settings.clone(),
+                            )?;
+                            if let ValidateCallbackResult::Valid = result {
+                                let original_reminder_settings: Option<ReminderSettings> = original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                                let original_reminder_settings = match original_reminder_settings {
+                                    Some(reminder_settings) => reminder_settings,
+                                    None => {
+                                        return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                "The updated entry type must be the same as the original entry type"
+                                                    .to_string(),
+                                            ),
+                                        );
+                                    }
+                                };
+                                validate_update_reminder_settings(
+                                    action,
+                                    reminder_settings,
+                                    original_action,
+                                    original_reminder_settings,
+                                )
+                            } else {
+                                Ok(result)
+                            }
                         }
-                    };
-                    match app_entry {
-                        EntryTypes::Invite(invite) => {
-                            let result = validate_create_invite(
+                        EntryTypes::FiredReminder(fired_reminder) => {
+                            let result = validate_create_fired_reminder(
                                 EntryCreationAction::Update(action.clone()),
-                                invite.clone(),
+                                fired_reminder.clone(),
                             )?;
                             if let ValidateCallbackResult::Valid = result {
-                                let original_invite: Option<Invite> = original_record
+                                let original_fired_reminder: Option<FiredReminder> = original_record
                                     .entry()
                                     .to_app_option()
                                     .map_err(|e| wasm_error!(e))?;
-                                let original_invite = match original_invite {
-                                    Some(invite) => invite,
+                                let original_fired_reminder = match original_fired_reminder {
+                                    Some(fired_reminder) => fired_reminder,
                                     None => {
                                         return Ok(
                                             ValidateCallbackResult::Invalid(
@@ -212,11 +981,73 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                                         );
                                     }
                                 };
-                                validate_update_invite(
+                                validate_update_fired_reminder(
                                     action,
-                                    invite,
+                                    fired_reminder,
                                     original_action,
-                                    original_invite,
+                                    original_fired_reminder,
+                                )
+                            } else {
+                                Ok(result)
+                            }
+                        }
+                        EntryTypes::NotificationRead(notification_read) => {
+                            let result = validate_create_notification_read(
+                                EntryCreationAction::Update(action.clone()),
+                                notification_read.clone(),
+                            )?;
+                            if let ValidateCallbackResult::Valid = result {
+                                let original_notification_read: Option<NotificationRead> = original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                                let original_notification_read = match original_notification_read {
+                                    Some(notification_read) => notification_read,
+                                    None => {
+                                        return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                "The updated entry type must be the same as the original entry type"
+                                                    .to_string(),
+                                            ),
+                                        );
+                                    }
+                                };
+                                validate_update_notification_read(
+                                    action,
+                                    notification_read,
+                                    original_action,
+                                    original_notification_read,
+                                )
+                            } else {
+                                Ok(result)
+                            }
+                        }
+                        EntryTypes::InvitationNotice(invitation_notice) => {
+                            let result = validate_create_invitation_notice(
+                                EntryCreationAction::Update(action.clone()),
+                                invitation_notice.clone(),
+                            )?;
+                            if let ValidateCallbackResult::Valid = result {
+                                let original_invitation_notice: Option<InvitationNotice> = original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                                let original_invitation_notice = match original_invitation_notice {
+                                    Some(invitation_notice) => invitation_notice,
+                                    None => {
+                                        return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                "The updated entry type must be the same as the original entry type"
+                                                    .to_string(),
+                                            ),
+                                        );
+                                    }
+                                };
+                                validate_update_invitation_notice(
+                                    action,
+                                    invitation_notice,
+                                    original_action,
+                                    original_invitation_notice,
                                 )
                             } else {
                                 Ok(result)
@@ -261,9 +1092,9 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                         }
                     };
                     let original_app_entry = match EntryTypes::deserialize_from_type(
-                        app_entry_type.zome_index.clone(),
-                        app_entry_type.entry_index.clone(),
-                        &entry,
+                        app_entry_type.zome_index,
+                        app_entry_type.entry_index,
+                        entry,
                     )? {
                         Some(app_entry) => app_entry,
                         None => {
@@ -283,6 +1114,76 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                                 original_invite,
                             )
                         }
+                        EntryTypes::TimeProposal(original_time_proposal) => {
+                            validate_delete_time_proposal(
+                                action,
+                                original_action,
+                                original_time_proposal,
+                            )
+                        }
+                        EntryTypes::OccurrenceException(original_occurrence_exception) => {
+                            validate_delete_occurrence_exception(
+                                action,
+                                original_action,
+                                original_occurrence_exception,
+                            )
+                        }
+                        EntryTypes::InviteTemplate(original_invite_template) => {
+                            validate_delete_invite_template(
+                                action,
+                                original_action,
+                                original_invite_template,
+                            )
+                        }
+                        EntryTypes::Group(original_group) => {
+                            validate_delete_group(
+                                action,
+                                original_action,
+                                original_group,
+                            )
+                        }
+                        EntryTypes::EncryptionKey(original_encryption_key) => {
+                            validate_delete_encryption_key(
+                                action,
+                                original_action,
+                                original_encryption_key,
+                            )
+                        }
+                        EntryTypes::Acceptance(original_acceptance) => {
+                            validate_delete_acceptance(
+                                action,
+                                original_action,
+                                original_acceptance,
+                            )
+                        }
+                        EntryTypes::ReminderSettings(original_reminder_settings) => {
+                            validate_delete_reminder_settings(
+                                action,
+                                original_action,
+                                original_reminder_settings,
+                            )
+                        }
+                        EntryTypes::FiredReminder(original_fired_reminder) => {
+                            validate_delete_fired_reminder(
+                                action,
+                                original_action,
+                                original_fired_reminder,
+                            )
+                        }
+                        EntryTypes::NotificationRead(original_notification_read) => {
+                            validate_delete_notification_read(
+                                action,
+                                original_action,
+                                original_notification_read,
+                            )
+                        }
+                        EntryTypes::InvitationNotice(original_invitation_notice) => {
+                            validate_delete_invitation_notice(
+                                action,
+                                original_action,
+                                original_invitation_notice,
+                            )
+                        }
                     }
                 }
                 OpRecord::CreateLink {
@@ -309,6 +1210,70 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                                 tag,
                             )
                         }
+                        LinkTypes::InviteToProposal => {
+                            validate_create_link_invite_to_proposal(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::InviteToException => {
+                            validate_create_link_invite_to_exception(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::InviteToRedeemer => {
+                            validate_create_link_invite_to_redeemer(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::GroupToInvite => {
+                            validate_create_link_group_to_invite(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::InviteToSlotVote => {
+                            validate_create_link_invite_to_slot_vote(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::AgentToEncryptionKey => {
+                            validate_create_link_agent_to_encryption_key(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::InviteToCheckIn => {
+                            validate_create_link_invite_to_check_in(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::AgentToNotification => {
+                            validate_create_link_agent_to_notification(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
                     }
                 }
                 OpRecord::DeleteLink { original_action_hash, base_address, action } => {
@@ -325,8 +1290,8 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                         }
                     };
                     let link_type = match LinkTypes::from_type(
-                        create_link.zome_index.clone(),
-                        create_link.link_type.clone(),
+                        create_link.zome_index,
+                        create_link.link_type,
                     )? {
                         Some(lt) => lt,
                         None => {
@@ -352,6 +1317,78 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                                 create_link.tag,
                             )
                         }
+                        LinkTypes::InviteToProposal => {
+                            validate_delete_link_invite_to_proposal(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::InviteToException => {
+                            validate_delete_link_invite_to_exception(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::InviteToRedeemer => {
+                            validate_delete_link_invite_to_redeemer(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::GroupToInvite => {
+                            validate_delete_link_group_to_invite(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::InviteToSlotVote => {
+                            validate_delete_link_invite_to_slot_vote(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::AgentToEncryptionKey => {
+                            validate_delete_link_agent_to_encryption_key(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::InviteToCheckIn => {
+                            validate_delete_link_invite_to_check_in(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::AgentToNotification => {
+                            validate_delete_link_agent_to_notification(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
                     }
                 }
                 OpRecord::CreatePrivateEntry { .. } => Ok(ValidateCallbackResult::Valid),
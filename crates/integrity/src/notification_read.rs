@@ -0,0 +1,30 @@
+use hdi::prelude::*;
+
+//a notification we read, kept privately on our own chain
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct NotificationRead {
+    pub notification_hash: ActionHash,
+}
+
+pub fn validate_create_notification_read(
+    _action: EntryCreationAction,
+    _notification_read: NotificationRead,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_update_notification_read(
+    _action: Update,
+    _notification_read: NotificationRead,
+    _original_action: EntryCreationAction,
+    _original_notification_read: NotificationRead,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid("Read notifications cannot be updated".into()))
+}
+pub fn validate_delete_notification_read(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_notification_read: NotificationRead,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid("Read notifications cannot be deleted".into()))
+}
@@ -0,0 +1,59 @@
+use hdi::prelude::*;
+use crate::{is_occurrence, must_get_invite_version};
+
+//a single occurrence of a recurring invitation that was cancelled or moved, the series itself is left as is
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct OccurrenceException {
+    pub creation_hash: ActionHash,
+    pub invite_action_hash: ActionHash,
+    pub original_start: Timestamp,
+    pub cancelled: bool,
+    pub start_time: Option<Timestamp>,
+    pub end_time: Option<Timestamp>,
+}
+
+//Input DTO from the UI, leave out the times to cancel the occurrence
+#[derive(Clone, Debug, Serialize, Deserialize, SerializedBytes)]
+pub struct OccurrenceExceptionInput {
+    pub creation_hash: ActionHash,
+    pub original_start: Timestamp,
+    #[serde(default)]
+    pub cancelled: bool,
+    pub start_time: Option<Timestamp>,
+    pub end_time: Option<Timestamp>,
+}
+
+pub fn validate_create_occurrence_exception(
+    _action: EntryCreationAction,
+    _occurrence_exception: OccurrenceException,
+) -> ExternResult<ValidateCallbackResult> {
+    let invite = must_get_invite_version(&_occurrence_exception.creation_hash, &_occurrence_exception.invite_action_hash)?;
+    let author = must_get_action(_occurrence_exception.creation_hash.clone())?.action().author().clone();
+    if &author != _action.author() && !invite.co_hosts.contains(_action.author()) {
+        Ok(ValidateCallbackResult::Invalid("only the hosts of an invitation can change its occurrences".into()))
+    } else if !is_occurrence(&invite, _occurrence_exception.original_start) {
+        Ok(ValidateCallbackResult::Invalid("the invitation does not recur at that time".into()))
+    } else if !_occurrence_exception.cancelled && _occurrence_exception.start_time.is_none() {
+        Ok(ValidateCallbackResult::Invalid("a moved occurrence needs a start time".into()))
+    } else if _occurrence_exception.start_time.is_some_and(|start_time| _occurrence_exception.end_time.is_some_and(|end_time| end_time < start_time)) {
+        Ok(ValidateCallbackResult::Invalid("the end time of the occurrence is before its start time".into()))
+    } else {
+        Ok(ValidateCallbackResult::Valid)
+    }
+}
+pub fn validate_update_occurrence_exception(
+    _action: Update,
+    _occurrence_exception: OccurrenceException,
+    _original_action: EntryCreationAction,
+    _original_occurrence_exception: OccurrenceException,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid("Occurrence exceptions cannot be updated, the latest exception of an occurrence counts".into()))
+}
+pub fn validate_delete_occurrence_exception(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_occurrence_exception: OccurrenceException,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid("Occurrence exceptions cannot be deleted, the latest exception of an occurrence counts".into()))
+}
@@ -0,0 +1,237 @@
+use hdi::prelude::*;
+use crate::Invite;
+
+const MICROS_PER_DAY: i64 = 86_400_000_000;
+//enough periods for the largest count, even for monthly rules that skip the months without their day
+const MAX_EXPANDED_PERIODS: i64 = 10_000;
+pub const MAX_RECURRENCE_COUNT: u32 = 1_000;
+//later years do not fit in a Timestamp
+const MAX_YEAR: i64 = 300_000;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Weekday {
+    Mon,
+    Tue,
+    Wed,
+    Thu,
+    Fri,
+    Sat,
+    Sun,
+}
+
+//RRULE-style recurrence of an invitation, the first occurrence is the start_time of the Invite.
+//by_day limits the occurrences to some weekdays, for monthly rules every such weekday of the month recurs.
+//times are in UTC
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RecurrenceRule {
+    pub frequency: Frequency,
+    pub interval: u32,
+    pub by_day: Vec<Weekday>,
+    pub count: Option<u32>,
+    pub until: Option<Timestamp>,
+}
+
+//Input DTO from the UI to list the occurrences of an invitation within a time window
+#[derive(Clone, Debug, Serialize, Deserialize, SerializedBytes)]
+pub struct OccurrencesInput {
+    pub creation_hash: ActionHash,
+    pub window_start: Timestamp,
+    pub window_end: Timestamp,
+}
+
+//Output DTO for a single occurrence, original_start identifies it even when it was moved
+#[derive(Clone, Debug, Serialize, Deserialize, SerializedBytes)]
+pub struct OccurrenceInfo {
+    pub original_start: Timestamp,
+    pub start_time: Timestamp,
+    pub end_time: Option<Timestamp>,
+    pub cancelled: bool,
+    pub moved: bool,
+    pub invitees_who_accepted: Vec<AgentPubKey>,
+    pub invitees_who_rejected: Vec<AgentPubKey>,
+    pub invitees_who_are_tentative: Vec<AgentPubKey>,
+    pub invitees_waitlisted: Vec<AgentPubKey>,
+    pub invitees_pending: Vec<AgentPubKey>,
+    pub invitees_checked_in: Vec<AgentPubKey>,
+}
+
+pub fn validate_recurrence_rule(invite: &Invite) -> Option<String> {
+    let rule = invite.recurrence.as_ref()?;
+    if invite.start_time.is_none() {
+        Some("a recurring invitation needs a start time".into())
+    } else if rule.interval == 0 {
+        Some("the interval of a recurrence must be at least 1".into())
+    } else if rule.count.is_some() && rule.until.is_some() {
+        Some("a recurrence can end after a count or on a date, not both".into())
+    } else if rule.count.is_some_and(|count| count > MAX_RECURRENCE_COUNT) {
+        Some(format!("a recurrence can have at most {} occurrences", MAX_RECURRENCE_COUNT))
+    } else {
+        None
+    }
+}
+
+//start times of the occurrences of a recurring invitation that start in the window, both ends included.
+//validators expand the times given in tags and exceptions, so the work is bounded whatever the window:
+//without a count the expansion starts at the period of window_start, at most MAX_EXPANDED_PERIODS are looked at
+//and times that do not fit in a Timestamp end the expansion
+pub fn expand_occurrences(invite: &Invite, window_start: Timestamp, window_end: Timestamp) -> Vec<Timestamp> {
+    let (rule, first_start) = match (&invite.recurrence, invite.start_time) {
+        (Some(rule), Some(first_start)) if rule.interval > 0 => (rule, first_start),
+        _ => return vec![],
+    };
+    let first_start = first_start.as_micros();
+    let last_start = match rule.until {
+        Some(until) => until.as_micros().min(window_end.as_micros()),
+        None => window_end.as_micros(),
+    };
+    let first_day = first_start.div_euclid(MICROS_PER_DAY);
+    let time_of_day = first_start.rem_euclid(MICROS_PER_DAY);
+
+    //occurrences before the window only matter when they are counted
+    let first_period = match rule.count {
+        Some(_) => 0,
+        None => get_period_of_day(rule, first_day, window_start.as_micros().div_euclid(MICROS_PER_DAY)),
+    };
+    let mut occurrences: Vec<Timestamp> = vec![];
+    let mut counted: u32 = 0;
+    for period in first_period..first_period.saturating_add(MAX_EXPANDED_PERIODS) {
+        let days = match get_period_days(rule, first_day, period) {
+            Some(days) => days,
+            None => return occurrences,
+        };
+        for day in days.into_iter() {
+            let start = match day.checked_mul(MICROS_PER_DAY).and_then(|micros| micros.checked_add(time_of_day)) {
+                Some(start) => start,
+                None => return occurrences,
+            };
+            if start < first_start {
+                continue;
+            }
+            if start > last_start || rule.count.is_some_and(|count| counted >= count) {
+                return occurrences;
+            }
+            if !rule.by_day.is_empty() && !rule.by_day.iter().any(|weekday| weekday_index(weekday) == weekday_of(day)) {
+                continue;
+            }
+            counted += 1;
+            if start >= window_start.as_micros() {
+                occurrences.push(Timestamp::from_micros(start));
+            }
+        }
+    }
+    occurrences
+}
+
+pub fn is_occurrence(invite: &Invite, start: Timestamp) -> bool {
+    expand_occurrences(invite, start, start).contains(&start)
+}
+
+//the days on which occurrences can fall in a period, None once they no longer fit in a Timestamp
+fn get_period_days(rule: &RecurrenceRule, first_day: i64, period: i64) -> Option<Vec<i64>> {
+    let periods = period.checked_mul(rule.interval as i64)?;
+    match rule.frequency {
+        Frequency::Daily => Some(vec![first_day.checked_add(periods)?]),
+        Frequency::Weekly => {
+            let monday = (first_day - weekday_of(first_day)).checked_add(periods.checked_mul(7)?)?;
+            if rule.by_day.is_empty() {
+                Some(vec![monday + weekday_of(first_day)])
+            } else {
+                Some((0..7).map(|offset| monday + offset).collect())
+            }
+        }
+        Frequency::Monthly => {
+            let (year, month, day) = civil_from_days(first_day);
+            let months = (year * 12 + (month - 1)).checked_add(periods)?;
+            let (year, month) = (months.div_euclid(12), months.rem_euclid(12) + 1);
+            if year > MAX_YEAR {
+                return None;
+            }
+            if rule.by_day.is_empty() {
+                //months without that day are skipped
+                if day <= days_in_month(year, month) {
+                    Some(vec![days_from_civil(year, month, day)])
+                } else {
+                    Some(vec![])
+                }
+            } else {
+                let first = days_from_civil(year, month, 1);
+                Some((0..days_in_month(year, month)).map(|offset| first + offset).collect())
+            }
+        }
+    }
+}
+
+//the last period starting on or before the day, the first one for days before the series
+fn get_period_of_day(rule: &RecurrenceRule, first_day: i64, day: i64) -> i64 {
+    let interval = rule.interval as i64;
+    let period = match rule.frequency {
+        Frequency::Daily => (day - first_day).div_euclid(interval),
+        Frequency::Weekly => ((day - weekday_of(day)) - (first_day - weekday_of(first_day))).div_euclid(interval * 7),
+        Frequency::Monthly => {
+            let (first_year, first_month, _) = civil_from_days(first_day);
+            let (year, month, _) = civil_from_days(day);
+            ((year * 12 + month) - (first_year * 12 + first_month)).div_euclid(interval)
+        }
+    };
+    period.max(0)
+}
+
+//0 is monday, the first of january 1970 was a thursday
+fn weekday_of(days: i64) -> i64 {
+    (days + 3).rem_euclid(7)
+}
+
+fn weekday_index(weekday: &Weekday) -> i64 {
+    match weekday {
+        Weekday::Mon => 0,
+        Weekday::Tue => 1,
+        Weekday::Wed => 2,
+        Weekday::Thu => 3,
+        Weekday::Fri => 4,
+        Weekday::Sat => 5,
+        Weekday::Sun => 6,
+    }
+}
+
+fn is_leap_year(year: i64) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+//conversions between days since 1970-01-01 and (year, month, day), see http://howardhinnant.github.io/date_algorithms.html
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days - era * 146097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
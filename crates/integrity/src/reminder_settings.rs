@@ -0,0 +1,42 @@
+use hdi::prelude::*;
+
+//how long before the start of an accepted invitation a reminder is given, in microseconds
+pub const DEFAULT_REMINDER_OFFSETS: [i64; 2] = [24 * 60 * 60 * 1_000_000, 15 * 60 * 1_000_000];
+
+//the private reminder offsets of an agent, in microseconds before the start time
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct ReminderSettings {
+    pub offsets: Vec<i64>,
+}
+
+pub fn validate_create_reminder_settings(
+    _action: EntryCreationAction,
+    _reminder_settings: ReminderSettings,
+) -> ExternResult<ValidateCallbackResult> {
+    if _reminder_settings.offsets.iter().any(|offset| *offset < 0) {
+        Ok(ValidateCallbackResult::Invalid("reminders can only be given before the start time".into()))
+    } else {
+        Ok(ValidateCallbackResult::Valid)
+    }
+}
+pub fn validate_update_reminder_settings(
+    _action: Update,
+    _reminder_settings: ReminderSettings,
+    _original_action: EntryCreationAction,
+    _original_reminder_settings: ReminderSettings,
+) -> ExternResult<ValidateCallbackResult> {
+    if _original_action.author() == &_action.author
+    {
+        Ok(ValidateCallbackResult::Valid)
+    } else {
+        Ok(ValidateCallbackResult::Invalid("Only the author of reminder settings can update them".into()))
+    }
+}
+pub fn validate_delete_reminder_settings(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_reminder_settings: ReminderSettings,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid("Reminder settings cannot be deleted, they can be updated".into()))
+}
@@ -0,0 +1,65 @@
+use hdi::prelude::*;
+use crate::must_get_invite_version;
+
+//an invitee proposing another time for an invitation
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct TimeProposal {
+    pub creation_hash: ActionHash,
+    pub invite_action_hash: ActionHash,
+    pub start_time: Timestamp,
+    pub end_time: Option<Timestamp>,
+    pub note: Option<String>,
+}
+
+//Input DTO from the UI
+#[derive(Clone, Debug, Serialize, Deserialize, SerializedBytes)]
+pub struct TimeProposalInput {
+    pub creation_hash: ActionHash,
+    pub start_time: Timestamp,
+    pub end_time: Option<Timestamp>,
+    pub note: Option<String>,
+}
+
+//Output DTO for the UI
+#[derive(Clone, Debug, Serialize, Deserialize, SerializedBytes)]
+pub struct TimeProposalInfo {
+    pub proposal: TimeProposal,
+    pub proposal_hash: ActionHash,
+    pub author: AgentPubKey,
+    pub timestamp: Timestamp,
+}
+
+pub fn validate_create_time_proposal(
+    _action: EntryCreationAction,
+    _time_proposal: TimeProposal,
+) -> ExternResult<ValidateCallbackResult> {
+    let invite = must_get_invite_version(&_time_proposal.creation_hash, &_time_proposal.invite_action_hash)?;
+    if !invite.invitees.contains(_action.author()) {
+        Ok(ValidateCallbackResult::Invalid("only invitees can propose a new time".into()))
+    } else if _time_proposal.end_time.is_some_and(|end_time| end_time < _time_proposal.start_time) {
+        Ok(ValidateCallbackResult::Invalid("the proposed end time is before the start time".into()))
+    } else {
+        Ok(ValidateCallbackResult::Valid)
+    }
+}
+pub fn validate_update_time_proposal(
+    _action: Update,
+    _time_proposal: TimeProposal,
+    _original_action: EntryCreationAction,
+    _original_time_proposal: TimeProposal,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid("Time proposals cannot be updated, make a new proposal instead".into()))
+}
+pub fn validate_delete_time_proposal(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_time_proposal: TimeProposal,
+) -> ExternResult<ValidateCallbackResult> {
+    if _original_action.author() == &_action.author
+    {
+        Ok(ValidateCallbackResult::Valid)
+    } else {
+        Ok(ValidateCallbackResult::Invalid("Only the author of a time proposal can delete it".into()))
+    }
+}
@@ -4,13 +4,83 @@ import { ActionHash, AgentPubKey, Timestamp } from '@holochain/client';
 export type Invite = {
   inviter: AgentPubKey,
   invitees: AgentPubKey[],
+  invitee_roles: InviteeRole[],
+  co_hosts: AgentPubKey[],
   location?: string,
   start_time?: Timestamp,
   end_time?: Timestamp,
   details?: Record<string, string>;
+  rsvp_deadline?: Timestamp,
+  max_attendees?: number,
+  min_attendees?: number,
+  allow_guests: boolean,
+  max_guests_per_invitee?: number,
+  recurrence?: RecurrenceRule,
+  invite_code?: InviteCode,
+  groups: ActionHash[],
+  slots: TimeSlot[],
+  encrypted_details?: EncryptedDetails,
+  countersign_acceptances: boolean,
+  require_check_in_token: boolean,
+  closed: boolean,
   timestamp: Timestamp
 }
 
+export type EncryptedDetails = {
+  sender_key: Uint8Array,
+  recipients: { agent: AgentPubKey, data: { nonce: Uint8Array, encrypted_data: Uint8Array } }[]
+}
+
+export type TimeSlot = {
+  start_time: Timestamp,
+  end_time?: Timestamp
+}
+
+export type SlotVote = "yes" | "maybe" | "no"
+
+export type SlotTally = {
+  slot: TimeSlot,
+  yes: AgentPubKey[],
+  maybe: AgentPubKey[],
+  no: AgentPubKey[]
+}
+
+export type InviteeRole = {
+  agent: AgentPubKey,
+  role: string,
+  required: boolean
+}
+
+export type RecurrenceRule = {
+  frequency: "Daily" | "Weekly" | "Monthly",
+  interval: number,
+  by_day: ("Mon" | "Tue" | "Wed" | "Thu" | "Fri" | "Sat" | "Sun")[],
+  count?: number,
+  until?: Timestamp
+}
+
+export type InviteCode = {
+  public_key: AgentPubKey,
+  max_redemptions?: number,
+  expires_at?: Timestamp
+}
+
+export type OccurrenceInfo = {
+  original_start: Timestamp,
+  start_time: Timestamp,
+  end_time?: Timestamp,
+  cancelled: boolean,
+  moved: boolean,
+  invitees_who_accepted: AgentPubKey[],
+  invitees_who_rejected: AgentPubKey[],
+  invitees_who_are_tentative: AgentPubKey[],
+  invitees_waitlisted: AgentPubKey[],
+  invitees_pending: AgentPubKey[],
+  invitees_checked_in: AgentPubKey[]
+}
+
+export type ResponseReason = "Conflict" | "Travel" | "Sick" | "Other"
+
 export type InviteInfo = {
   invitation: Invite,
   creation_hash: ActionHash,
@@ -18,16 +88,115 @@ export type InviteInfo = {
   timestamp: Timestamp
   invitees_who_accepted: AgentPubKey[],
   invitees_who_rejected: AgentPubKey[],
-  invitees_pending: AgentPubKey[]
+  invitees_who_are_tentative: AgentPubKey[],
+  invitees_waitlisted: AgentPubKey[],
+  invitees_pending: AgentPubKey[],
+  invitees_redeemed: AgentPubKey[],
+  invitees_checked_in: AgentPubKey[],
+  invitees_delivered?: AgentPubKey[],
+  invitee_roles: InviteeRole[],
+  slot_tallies: SlotTally[],
+  required_invitees_who_accepted: AgentPubKey[],
+  optional_invitees_who_accepted: AgentPubKey[],
+  required_invitees_who_rejected: AgentPubKey[],
+  optional_invitees_who_rejected: AgentPubKey[],
+  headcount: number,
+  invitee_responses: InviteResponse[],
+  cancelled: boolean,
+  expired: boolean,
+  quorum_reached: boolean,
+  did_not_happen: boolean
+}
+
+export type InviteResponse = {
+  invitee: AgentPubKey,
+  response: string,
+  guests: number,
+  note?: string,
+  reason?: ResponseReason,
+  occurrence?: Timestamp,
+  invite_action_hash: ActionHash,
+  timestamp: Timestamp,
+  response_hash: ActionHash,
+  countersigned: boolean
+}
+
+export type TimeProposal = {
+  creation_hash: ActionHash,
+  invite_action_hash: ActionHash,
+  start_time: Timestamp,
+  end_time?: Timestamp,
+  note?: string
+}
 
+export type TimeProposalInfo = {
+  proposal: TimeProposal,
+  proposal_hash: ActionHash,
+  author: AgentPubKey,
+  timestamp: Timestamp
+}
+
+export type InviteTemplate = {
+  name: string,
+  invitees: AgentPubKey[],
+  location?: string,
+  duration?: number,
+  details?: Record<string, string>
+}
+
+export type InviteTemplateInfo = {
+  template: InviteTemplate,
+  template_hash: ActionHash,
+  timestamp: Timestamp
+}
+
+export type Group = {
+  name: string,
+  members: AgentPubKey[]
+}
+
+export type GroupInfo = {
+  group: Group,
+  group_hash: ActionHash,
+  author: AgentPubKey,
+  timestamp: Timestamp
+}
+
+export type CheckInToken = {
+  host: AgentPubKey,
+  signature: Uint8Array
+}
+
+export type Notification = {
+  notification_hash: ActionHash,
+  kind: string,
+  creation_hash: ActionHash,
+  action_hash: ActionHash,
+  sender: AgentPubKey,
+  timestamp: Timestamp,
+  read: boolean
 }
 
 export type InviteInput = {
   invitees: AgentPubKey[],
+  invitee_roles?: InviteeRole[],
+  co_hosts?: AgentPubKey[],
   location?: string,
   start_time?: Timestamp,
   end_time?: Timestamp,
   details?: Record<string, string>;
+  rsvp_deadline?: Timestamp,
+  max_attendees?: number,
+  min_attendees?: number,
+  allow_guests?: boolean,
+  max_guests_per_invitee?: number,
+  recurrence?: RecurrenceRule,
+  invite_code?: InviteCode,
+  groups?: ActionHash[],
+  slots?: TimeSlot[],
+  encrypt_details?: boolean,
+  countersign_acceptances?: boolean,
+  require_check_in_token?: boolean,
   creation_hash?: ActionHash
 }
 
@@ -55,11 +224,11 @@ export async function updateInvitation(cell: CallableCell, invitation:InviteInpu
   });
 }
 
-export async function getPendingInvites(cell: CallableCell): Promise<InviteInfo[]> {
+export async function getPendingInvites(cell: CallableCell, role?: string): Promise<InviteInfo[]> {
   return cell.callZome({
     zome_name: "invitations",
     fn_name: "get_my_pending_invitations",
-    payload: null
+    payload: role ?? null
   });
 }
 
@@ -71,22 +240,46 @@ export async function getAllInvites(cell: CallableCell): Promise<InviteInfo[]> {
   });
 }
 
-export async function acceptInvite(cell: CallableCell, creationHash:ActionHash): Promise<ActionHash> {
+export async function acceptInvite(cell: CallableCell, creationHash:ActionHash, guests: number = 0, note?: string, reason?: ResponseReason, occurrence?: Timestamp, acceptanceHash?: ActionHash): Promise<ActionHash> {
   return cell.callZome({
     zome_name: "invitations",
     fn_name: "accept_invitation",
-    payload: creationHash
+    payload: { creation_hash: creationHash, guests, note, reason, occurrence, acceptance_hash: acceptanceHash }
   });
 }
 
-export async function rejectInvite(cell:CallableCell, creationHash: ActionHash): Promise<ActionHash> {
+export async function rejectInvite(cell:CallableCell, creationHash: ActionHash, note?: string, reason?: ResponseReason, occurrence?: Timestamp): Promise<ActionHash> {
   return cell.callZome({
     zome_name: "invitations",
     fn_name: "reject_invitation",
+    payload: { creation_hash: creationHash, note, reason, occurrence }
+  });
+}
+
+export async function getResponseHistory(cell:CallableCell, creationHash: ActionHash): Promise<InviteResponse[]> {
+  return cell.callZome({
+    zome_name: "invitations",
+    fn_name: "get_invitation_response_history",
     payload: creationHash
   });
 }
 
+export async function cancelInvite(cell:CallableCell, creationHash: ActionHash): Promise<ActionHash> {
+  return cell.callZome({
+    zome_name: "invitations",
+    fn_name: "cancel_invitation",
+    payload: creationHash
+  });
+}
+
+export async function tentativeInvite(cell:CallableCell, creationHash: ActionHash, note?: string, reason?: ResponseReason, occurrence?: Timestamp): Promise<ActionHash> {
+  return cell.callZome({
+    zome_name: "invitations",
+    fn_name: "tentative_invitation",
+    payload: { creation_hash: creationHash, note, reason, occurrence }
+  });
+}
+
 export async function clearInvite(cell:CallableCell, creationHash: ActionHash): Promise<void> {
   return cell.callZome({
     zome_name: "invitations",
@@ -95,3 +288,219 @@ export async function clearInvite(cell:CallableCell, creationHash: ActionHash):
   });
 }
 
+
+export async function proposeNewTime(cell:CallableCell, creationHash: ActionHash, startTime: Timestamp, endTime?: Timestamp, note?: string): Promise<TimeProposalInfo> {
+  return cell.callZome({
+    zome_name: "invitations",
+    fn_name: "propose_new_time",
+    payload: { creation_hash: creationHash, start_time: startTime, end_time: endTime, note }
+  });
+}
+
+export async function getTimeProposals(cell:CallableCell, creationHash: ActionHash): Promise<TimeProposalInfo[]> {
+  return cell.callZome({
+    zome_name: "invitations",
+    fn_name: "get_time_proposals",
+    payload: creationHash
+  });
+}
+
+export async function acceptProposal(cell:CallableCell, proposalHash: ActionHash): Promise<InviteInfo> {
+  return cell.callZome({
+    zome_name: "invitations",
+    fn_name: "accept_proposal",
+    payload: proposalHash
+  });
+}
+
+export async function getOccurrences(cell:CallableCell, creationHash: ActionHash, windowStart: Timestamp, windowEnd: Timestamp): Promise<OccurrenceInfo[]> {
+  return cell.callZome({
+    zome_name: "invitations",
+    fn_name: "get_occurrences",
+    payload: { creation_hash: creationHash, window_start: windowStart, window_end: windowEnd }
+  });
+}
+
+export async function addOccurrenceException(cell:CallableCell, creationHash: ActionHash, originalStart: Timestamp, cancelled: boolean, startTime?: Timestamp, endTime?: Timestamp): Promise<ActionHash> {
+  return cell.callZome({
+    zome_name: "invitations",
+    fn_name: "add_occurrence_exception",
+    payload: { creation_hash: creationHash, original_start: originalStart, cancelled, start_time: startTime, end_time: endTime }
+  });
+}
+
+export async function createInviteTemplate(cell:CallableCell, template: InviteTemplate): Promise<InviteTemplateInfo> {
+  return cell.callZome({
+    zome_name: "invitations",
+    fn_name: "create_invite_template",
+    payload: template
+  });
+}
+
+export async function getMyInviteTemplates(cell:CallableCell): Promise<InviteTemplateInfo[]> {
+  return cell.callZome({
+    zome_name: "invitations",
+    fn_name: "get_my_invite_templates",
+    payload: null
+  });
+}
+
+export async function updateInviteTemplate(cell:CallableCell, templateHash: ActionHash, template: InviteTemplate): Promise<InviteTemplateInfo> {
+  return cell.callZome({
+    zome_name: "invitations",
+    fn_name: "update_invite_template",
+    payload: { template_hash: templateHash, template }
+  });
+}
+
+export async function deleteInviteTemplate(cell:CallableCell, templateHash: ActionHash): Promise<ActionHash> {
+  return cell.callZome({
+    zome_name: "invitations",
+    fn_name: "delete_invite_template",
+    payload: templateHash
+  });
+}
+
+export async function createInvitationFromTemplate(cell:CallableCell, templateHash: ActionHash, startTime: Timestamp): Promise<InviteInfo> {
+  return cell.callZome({
+    zome_name: "invitations",
+    fn_name: "create_invitation_from_template",
+    payload: { template_hash: templateHash, start_time: startTime }
+  });
+}
+
+export async function closeInvite(cell:CallableCell, creationHash: ActionHash): Promise<InviteInfo> {
+  return cell.callZome({
+    zome_name: "invitations",
+    fn_name: "close_invitation",
+    payload: creationHash
+  });
+}
+
+export async function redeemInviteCode(cell:CallableCell, creationHash: ActionHash, signature: Uint8Array): Promise<InviteInfo> {
+  return cell.callZome({
+    zome_name: "invitations",
+    fn_name: "redeem_invite_code",
+    payload: { creation_hash: creationHash, signature }
+  });
+}
+
+export async function createGroup(cell:CallableCell, group: Group): Promise<GroupInfo> {
+  return cell.callZome({
+    zome_name: "invitations",
+    fn_name: "create_group",
+    payload: group
+  });
+}
+
+export async function getMyGroups(cell:CallableCell): Promise<GroupInfo[]> {
+  return cell.callZome({
+    zome_name: "invitations",
+    fn_name: "get_my_groups",
+    payload: null
+  });
+}
+
+export async function updateGroup(cell:CallableCell, groupHash: ActionHash, group: Group, propagate: boolean): Promise<GroupInfo> {
+  return cell.callZome({
+    zome_name: "invitations",
+    fn_name: "update_group",
+    payload: { group_hash: groupHash, group, propagate }
+  });
+}
+
+export async function deleteGroup(cell:CallableCell, groupHash: ActionHash): Promise<ActionHash> {
+  return cell.callZome({
+    zome_name: "invitations",
+    fn_name: "delete_group",
+    payload: groupHash
+  });
+}
+
+export async function voteOnSlot(cell:CallableCell, creationHash: ActionHash, slot: TimeSlot, vote: SlotVote): Promise<ActionHash> {
+  return cell.callZome({
+    zome_name: "invitations",
+    fn_name: "vote_on_slot",
+    payload: { creation_hash: creationHash, slot, vote }
+  });
+}
+
+export async function finalizeSlot(cell:CallableCell, creationHash: ActionHash, slot: TimeSlot): Promise<InviteInfo> {
+  return cell.callZome({
+    zome_name: "invitations",
+    fn_name: "finalize_slot",
+    payload: { creation_hash: creationHash, slot }
+  });
+}
+
+export async function publishEncryptionKey(cell:CallableCell): Promise<Uint8Array> {
+  return cell.callZome({
+    zome_name: "invitations",
+    fn_name: "publish_encryption_key",
+    payload: null
+  });
+}
+
+export async function countersignAcceptance(cell:CallableCell, creationHash: ActionHash): Promise<ActionHash> {
+  return cell.callZome({
+    zome_name: "invitations",
+    fn_name: "countersign_acceptance",
+    payload: creationHash
+  });
+}
+
+export async function checkIn(cell:CallableCell, creationHash: ActionHash, token?: CheckInToken, occurrence?: Timestamp): Promise<InviteInfo> {
+  return cell.callZome({
+    zome_name: "invitations",
+    fn_name: "check_in",
+    payload: { creation_hash: creationHash, token, occurrence }
+  });
+}
+
+export async function issueCheckInToken(cell:CallableCell, creationHash: ActionHash, invitee: AgentPubKey): Promise<CheckInToken> {
+  return cell.callZome({
+    zome_name: "invitations",
+    fn_name: "issue_check_in_token",
+    payload: { creation_hash: creationHash, invitee }
+  });
+}
+
+export async function setReminderOffsets(cell:CallableCell, offsets: number[]): Promise<number[]> {
+  return cell.callZome({
+    zome_name: "invitations",
+    fn_name: "set_reminder_offsets",
+    payload: offsets
+  });
+}
+
+export async function getReminderOffsets(cell:CallableCell): Promise<number[]> {
+  return cell.callZome({
+    zome_name: "invitations",
+    fn_name: "get_reminder_offsets",
+    payload: null
+  });
+}
+
+export async function getNotifications(cell:CallableCell): Promise<Notification[]> {
+  return cell.callZome({
+    zome_name: "invitations",
+    fn_name: "get_notifications",
+    payload: null
+  });
+}
+
+export async function markNotificationRead(cell:CallableCell, notificationHash: ActionHash): Promise<void> {
+  return cell.callZome({
+    zome_name: "invitations",
+    fn_name: "mark_notification_read",
+    payload: notificationHash
+  });
+}
+
+export async function getUnreadNotificationCount(cell:CallableCell): Promise<number> {
+  return cell.callZome({
+    zome_name: "invitations",
+    fn_name: "get_unread_notification_count",
+    payload: null
+  });
+}
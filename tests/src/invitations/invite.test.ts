@@ -1,8 +1,9 @@
 import { assert, test } from "vitest";
 
 import { runScenario, dhtSync } from '@holochain/tryorama';
-import { AppSignalCb, AppSignal, encodeHashToBase64 } from '@holochain/client';
-import { acceptInvite, clearInvite, getAllInvites, getPendingInvites, getSampleInviteInput, getSampleInviteInputUpdate, InviteInfo, rejectInvite, sendInvitations, updateInvitation } from './common.js';
+import { ActionHash, AppSignalCb, AppSignal, encodeHashToBase64, generateSigningKeyPair } from '@holochain/client';
+import sodium from 'libsodium-wrappers';
+import { acceptInvite, acceptProposal, addOccurrenceException, cancelInvite, checkIn, clearInvite, countersignAcceptance, createGroup, createInvitationFromTemplate, createInviteTemplate, deleteInviteTemplate, finalizeSlot, getAllInvites, getMyGroups, getMyInviteTemplates, getNotifications, getOccurrences, getPendingInvites, getReminderOffsets, getTimeProposals, getResponseHistory, getSampleInviteInput, getSampleInviteInputUpdate, getUnreadNotificationCount, InviteInfo, issueCheckInToken, markNotificationRead, proposeNewTime, publishEncryptionKey, redeemInviteCode, rejectInvite, sendInvitations, setReminderOffsets, tentativeInvite, updateGroup, updateInvitation, updateInviteTemplate, voteOnSlot } from './common.js';
 
 const path_to_happ = '/../workdir/happ/invitations.happ'
 
@@ -356,3 +357,1130 @@ test('6. create, reject and clear Invite', async () => {
     assert.isNull(invite_list_bob)
   });
 });
+
+
+test('9. create and cancel Invite', async () => {
+  await runScenario(async scenario => {
+
+    // setup signal receivers
+    let signalReceived_bob:Promise<AppSignal>
+    let SignalHandler_bob: AppSignalCb | undefined;
+    SignalHandler_bob = (signal) => {
+      console.log("signal found for bob:",signal)
+      signalReceived_bob = new Promise<AppSignal>((resolve) => {
+        resolve(signal);
+      });
+    };
+
+    const testAppPath = process.cwd() + path_to_happ;
+
+    // Add 2 players with the test app to the Scenario.
+    const alice = await scenario.addPlayerWithApp({ path: testAppPath })
+    const bob = await scenario.addPlayerWithApp({ path: testAppPath })
+
+    //workaround hack to get signals to work
+    const appWs_bob = await bob.conductor.connectAppWs(await bob.conductor.attachAppInterface())
+    appWs_bob.on("signal", SignalHandler_bob);
+
+    await scenario.shareAllAgents();
+
+    console.log("\n************************* START TEST 9 ****************************\n")
+
+    console.log("\nAlice creates an Invite to Bob\n")
+    const invite_detail: InviteInfo = await sendInvitations(alice.cells[0],getSampleInviteInput([bob.agentPubKey,alice.agentPubKey]));
+    assert.ok(invite_detail);
+
+    await dhtSync([alice, bob], bob.cells[0].cell_id[0]);
+    let bob_signal = await signalReceived_bob
+    assert.equal(bob_signal.payload['type'], 'InvitationReceived')
+
+    console.log("\nAlice cancels the invitation\n")
+    const cancel = await cancelInvite(alice.cells[0], invite_detail.creation_hash)
+    console.log("cancel hash:",encodeHashToBase64(cancel))
+
+    await dhtSync([alice, bob], bob.cells[0].cell_id[0]);
+    let bob_signal2 = await signalReceived_bob
+
+    console.log("Bob sees he has been signalled a cancelled Invite:\n",bob_signal2.payload['data'])
+    assert.equal(bob_signal2.payload['type'], 'InvitationCancelled')
+
+    console.log("Bob checks his pending invites and sees the invitation is cancelled")
+    const invite_list_bob: InviteInfo[] = await getPendingInvites(bob.cells[0])
+    console.log(invite_list_bob)
+    assert.isTrue(invite_list_bob[0].cancelled)
+  });
+});
+
+
+test('11. update the invitees of an Invite', async () => {
+  await runScenario(async scenario => {
+
+    // setup signal receivers
+    let signalReceived_bob:Promise<AppSignal>
+    let SignalHandler_bob: AppSignalCb | undefined;
+    SignalHandler_bob = (signal) => {
+      console.log("signal found for bob:",signal)
+      signalReceived_bob = new Promise<AppSignal>((resolve) => {
+        resolve(signal);
+      });
+    };
+
+    let signalReceived_carol:Promise<AppSignal>
+    let SignalHandler_carol: AppSignalCb | undefined;
+    SignalHandler_carol = (signal) => {
+      console.log("signal found for carol:",signal)
+      signalReceived_carol = new Promise<AppSignal>((resolve) => {
+        resolve(signal);
+      });
+    };
+
+    const testAppPath = process.cwd() + path_to_happ;
+
+    // Add 3 players with the test app to the Scenario.
+    const alice = await scenario.addPlayerWithApp({ path: testAppPath })
+    const bob = await scenario.addPlayerWithApp({ path: testAppPath })
+    const carol = await scenario.addPlayerWithApp({ path: testAppPath })
+
+    //workaround hack to get signals to work
+    const appWs_bob = await bob.conductor.connectAppWs(await bob.conductor.attachAppInterface())
+    appWs_bob.on("signal", SignalHandler_bob);
+
+    const appWs_carol = await carol.conductor.connectAppWs(await carol.conductor.attachAppInterface())
+    appWs_carol.on("signal", SignalHandler_carol);
+
+    await scenario.shareAllAgents();
+
+    console.log("\n************************* START TEST 11 ****************************\n")
+
+    console.log("\nAlice creates an Invite to Bob\n")
+    const invite_detail: InviteInfo = await sendInvitations(alice.cells[0],getSampleInviteInput([bob.agentPubKey,alice.agentPubKey]));
+    assert.ok(invite_detail);
+
+    await dhtSync([alice, bob, carol], bob.cells[0].cell_id[0]);
+    let bob_signal = await signalReceived_bob
+    assert.equal(bob_signal.payload['type'], 'InvitationReceived')
+
+    console.log("\nAlice replaces Bob with Carol in the invitees\n")
+    let inviteUpdate = getSampleInviteInputUpdate([carol.agentPubKey,alice.agentPubKey], invite_detail.creation_hash)
+    const invite_detail_update: InviteInfo = await updateInvitation(alice.cells[0],inviteUpdate)
+    assert.deepEqual(invite_detail_update.invitees_pending, [carol.agentPubKey])
+
+    await dhtSync([alice, bob, carol], carol.cells[0].cell_id[0]);
+    let carol_signal = await signalReceived_carol
+    console.log("Carol sees the Invite has been signalled:\n",carol_signal.payload['data'])
+    assert.equal(carol_signal.payload['type'], 'InvitationReceived')
+
+    let bob_signal2 = await signalReceived_bob
+    console.log("Bob sees he has been removed from the Invite:\n",bob_signal2.payload['data'])
+    assert.equal(bob_signal2.payload['type'], 'InvitationRevoked')
+
+    const invite_list_carol: InviteInfo[] = await getPendingInvites(carol.cells[0])
+    assert.equal(invite_list_carol.length, 1)
+
+    const invite_list_bob = await getPendingInvites(bob.cells[0])
+    assert.isNull(invite_list_bob)
+
+    console.log("\nCarol accepts the invite after being added\n")
+    const accept = await acceptInvite(carol.cells[0], invite_detail.creation_hash)
+    assert.isDefined(accept)
+  });
+});
+
+
+test('12. accept and then reject an Invite', async () => {
+  await runScenario(async scenario => {
+
+    // setup signal receivers
+    let SignalHandler_alice: AppSignalCb | undefined;
+    let signalReceived_alice: Promise<AppSignal>
+    SignalHandler_alice = (signal) => {
+      console.log("signal found for Alice:",signal)
+      signalReceived_alice = new Promise<AppSignal>((resolve) => {
+        resolve(signal);
+      });
+    };
+
+    const testAppPath = process.cwd() + path_to_happ;
+
+    // Add 2 players with the test app to the Scenario.
+    const alice = await scenario.addPlayerWithApp({ path: testAppPath })
+    const bob = await scenario.addPlayerWithApp({ path: testAppPath })
+
+    //workaround hack to get signals to work
+    const appWs_alice = await alice.conductor.connectAppWs(await alice.conductor.attachAppInterface())
+    appWs_alice.on("signal", SignalHandler_alice);
+
+    await scenario.shareAllAgents();
+
+    console.log("\n************************* START TEST 12 ****************************\n")
+
+    console.log("\nAlice creates an Invite to Bob\n")
+    const invite_detail: InviteInfo = await sendInvitations(alice.cells[0],getSampleInviteInput([bob.agentPubKey,alice.agentPubKey]));
+    assert.ok(invite_detail);
+
+    await dhtSync([alice, bob], bob.cells[0].cell_id[0]);
+
+    console.log("\nBob accepts the invitation\n")
+    await acceptInvite(bob.cells[0], invite_detail.creation_hash)
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+    let alice_signal = await signalReceived_alice
+    assert.equal(alice_signal.payload['type'],'InvitationAccepted')
+
+    console.log("\nBob changes his mind and rejects the invitation\n")
+    await rejectInvite(bob.cells[0], invite_detail.creation_hash, "something came up", "Conflict")
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+    let alice_signal2 = await signalReceived_alice
+    assert.equal(alice_signal2.payload['type'],'ResponseChanged',"message should be of type response changed")
+    assert.equal(alice_signal2.payload['response'].response, 'rejected')
+    assert.equal(alice_signal2.payload['response'].note, 'something came up')
+    assert.equal(alice_signal2.payload['response'].reason, 'Conflict')
+    assert.isEmpty(alice_signal2.payload['data'].invitees_who_accepted)
+    assert.deepEqual(alice_signal2.payload['data'].invitees_who_rejected, [bob.agentPubKey])
+
+    console.log("Alice checks the response history of the invite\n")
+    const history = await getResponseHistory(alice.cells[0], invite_detail.creation_hash)
+    console.log(history)
+    assert.deepEqual(history.map(response => response.response), ["accepted", "rejected"])
+
+    const invite_list_bob: InviteInfo[] = await getAllInvites(bob.cells[0])
+    assert.equal(invite_list_bob.length, 1, "Bob should only have one link to the invitation")
+  });
+});
+
+
+test('13. respond tentatively to an Invite', async () => {
+  await runScenario(async scenario => {
+
+    // setup signal receivers
+    let SignalHandler_alice: AppSignalCb | undefined;
+    let signalReceived_alice: Promise<AppSignal>
+    SignalHandler_alice = (signal) => {
+      console.log("signal found for Alice:",signal)
+      signalReceived_alice = new Promise<AppSignal>((resolve) => {
+        resolve(signal);
+      });
+    };
+
+    const testAppPath = process.cwd() + path_to_happ;
+
+    // Add 2 players with the test app to the Scenario.
+    const alice = await scenario.addPlayerWithApp({ path: testAppPath })
+    const bob = await scenario.addPlayerWithApp({ path: testAppPath })
+
+    //workaround hack to get signals to work
+    const appWs_alice = await alice.conductor.connectAppWs(await alice.conductor.attachAppInterface())
+    appWs_alice.on("signal", SignalHandler_alice);
+
+    await scenario.shareAllAgents();
+
+    console.log("\n************************* START TEST 13 ****************************\n")
+
+    console.log("\nAlice creates an Invite to Bob\n")
+    const invite_detail: InviteInfo = await sendInvitations(alice.cells[0],getSampleInviteInput([bob.agentPubKey,alice.agentPubKey]));
+    assert.ok(invite_detail);
+
+    await dhtSync([alice, bob], bob.cells[0].cell_id[0]);
+
+    console.log("\nBob responds with maybe\n")
+    await tentativeInvite(bob.cells[0], invite_detail.creation_hash, "might be travelling", "Travel")
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    let alice_signal = await signalReceived_alice
+    assert.equal(alice_signal.payload['type'],'InvitationTentative',"message should be of type tentative")
+    assert.equal(alice_signal.payload['response'].note, 'might be travelling')
+    assert.equal(alice_signal.payload['response'].reason, 'Travel')
+    assert.deepEqual(alice_signal.payload['data'].invitees_who_are_tentative, [bob.agentPubKey])
+    assert.deepEqual(alice_signal.payload['data'].invitees_pending, [alice.agentPubKey])
+  });
+});
+
+
+test('15. accept an Invite with limited places and get promoted from the waitlist', async () => {
+  await runScenario(async scenario => {
+
+    // setup signal receivers
+    let signalReceived_carol:Promise<AppSignal>
+    let SignalHandler_carol: AppSignalCb | undefined;
+    SignalHandler_carol = (signal) => {
+      console.log("signal found for carol:",signal)
+      signalReceived_carol = new Promise<AppSignal>((resolve) => {
+        resolve(signal);
+      });
+    };
+
+    const testAppPath = process.cwd() + path_to_happ;
+
+    // Add 3 players with the test app to the Scenario.
+    const alice = await scenario.addPlayerWithApp({ path: testAppPath })
+    const bob = await scenario.addPlayerWithApp({ path: testAppPath })
+    const carol = await scenario.addPlayerWithApp({ path: testAppPath })
+
+    //workaround hack to get signals to work
+    const appWs_carol = await carol.conductor.connectAppWs(await carol.conductor.attachAppInterface())
+    appWs_carol.on("signal", SignalHandler_carol);
+
+    await scenario.shareAllAgents();
+
+    console.log("\n************************* START TEST 15 ****************************\n")
+
+    console.log("\nAlice creates an Invite for two attendees to Bob and Carol\n")
+    const invite_input = { ...getSampleInviteInput([bob.agentPubKey,carol.agentPubKey]), max_attendees: 2, allow_guests: true, max_guests_per_invitee: 1 }
+    const invite_detail: InviteInfo = await sendInvitations(alice.cells[0],invite_input);
+    assert.ok(invite_detail);
+
+    await dhtSync([alice, bob, carol], alice.cells[0].cell_id[0]);
+
+    console.log("\nBob accepts with a guest and then Carol accepts the invitation\n")
+    await acceptInvite(bob.cells[0], invite_detail.creation_hash, 1)
+    await dhtSync([alice, bob, carol], alice.cells[0].cell_id[0]);
+    await acceptInvite(carol.cells[0], invite_detail.creation_hash)
+    await dhtSync([alice, bob, carol], alice.cells[0].cell_id[0]);
+
+    const invite_list_alice: InviteInfo[] = await getAllInvites(alice.cells[0])
+    assert.deepEqual(invite_list_alice[0].invitees_who_accepted, [bob.agentPubKey])
+    assert.deepEqual(invite_list_alice[0].invitees_waitlisted, [carol.agentPubKey])
+    assert.equal(invite_list_alice[0].headcount, 2)
+
+    console.log("\nBob accepts again with a note and keeps the place\n")
+    await acceptInvite(bob.cells[0], invite_detail.creation_hash, 1, "bringing a friend")
+    await dhtSync([alice, bob, carol], alice.cells[0].cell_id[0]);
+
+    const invite_list_alice2: InviteInfo[] = await getAllInvites(alice.cells[0])
+    assert.deepEqual(invite_list_alice2[0].invitees_who_accepted, [bob.agentPubKey])
+    assert.deepEqual(invite_list_alice2[0].invitees_waitlisted, [carol.agentPubKey])
+
+    console.log("\nBob declines and Carol takes the free place\n")
+    await rejectInvite(bob.cells[0], invite_detail.creation_hash)
+    await dhtSync([alice, bob, carol], carol.cells[0].cell_id[0]);
+
+    let carol_signal = await signalReceived_carol
+    assert.equal(carol_signal.payload['type'], 'WaitlistPromoted')
+    assert.deepEqual(carol_signal.payload['data'].invitees_who_accepted, [carol.agentPubKey])
+    assert.isEmpty(carol_signal.payload['data'].invitees_waitlisted)
+  });
+});
+
+
+test('17. reject an Invite giving a reason', async () => {
+  await runScenario(async scenario => {
+
+    // setup signal receivers
+    let SignalHandler_alice: AppSignalCb | undefined;
+    let signalReceived_alice: Promise<AppSignal>
+    SignalHandler_alice = (signal) => {
+      console.log("signal found for Alice:",signal)
+      signalReceived_alice = new Promise<AppSignal>((resolve) => {
+        resolve(signal);
+      });
+    };
+
+    const testAppPath = process.cwd() + path_to_happ;
+
+    // Add 2 players with the test app to the Scenario.
+    const alice = await scenario.addPlayerWithApp({ path: testAppPath })
+    const bob = await scenario.addPlayerWithApp({ path: testAppPath })
+
+    //workaround hack to get signals to work
+    const appWs_alice = await alice.conductor.connectAppWs(await alice.conductor.attachAppInterface())
+    appWs_alice.on("signal", SignalHandler_alice);
+
+    await scenario.shareAllAgents();
+
+    console.log("\n************************* START TEST 17 ****************************\n")
+
+    console.log("\nAlice creates an Invite to Bob\n")
+    const invite_detail: InviteInfo = await sendInvitations(alice.cells[0],getSampleInviteInput([bob.agentPubKey,alice.agentPubKey]));
+    assert.ok(invite_detail);
+
+    await dhtSync([alice, bob], bob.cells[0].cell_id[0]);
+
+    console.log("\nBob rejects the invitation because he is travelling\n")
+    await rejectInvite(bob.cells[0], invite_detail.creation_hash, "I will be in Lisbon that week", "Travel")
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    let alice_signal = await signalReceived_alice
+    assert.equal(alice_signal.payload['type'],'InvitationRejected')
+    assert.equal(alice_signal.payload['response'].reason, 'Travel')
+    assert.equal(alice_signal.payload['response'].note, 'I will be in Lisbon that week')
+
+    const invite_list_alice: InviteInfo[] = await getAllInvites(alice.cells[0])
+    const bob_response = invite_list_alice[0].invitee_responses.find(response => response.response === "rejected")
+    assert.equal(bob_response?.reason, 'Travel')
+  });
+});
+
+
+test('18. propose a new time for an Invite and have the author accept it', async () => {
+  await runScenario(async scenario => {
+
+    // setup signal receivers
+    let SignalHandler_alice: AppSignalCb | undefined;
+    let signalReceived_alice: Promise<AppSignal>
+    SignalHandler_alice = (signal) => {
+      console.log("signal found for Alice:",signal)
+      signalReceived_alice = new Promise<AppSignal>((resolve) => {
+        resolve(signal);
+      });
+    };
+
+    const testAppPath = process.cwd() + path_to_happ;
+
+    // Add 3 players with the test app to the Scenario.
+    const alice = await scenario.addPlayerWithApp({ path: testAppPath })
+    const bob = await scenario.addPlayerWithApp({ path: testAppPath })
+    const carol = await scenario.addPlayerWithApp({ path: testAppPath })
+
+    //workaround hack to get signals to work
+    const appWs_alice = await alice.conductor.connectAppWs(await alice.conductor.attachAppInterface())
+    appWs_alice.on("signal", SignalHandler_alice);
+
+    await scenario.shareAllAgents();
+
+    console.log("\n************************* START TEST 18 ****************************\n")
+
+    console.log("\nAlice creates an Invite to Bob and Carol\n")
+    const invite_detail: InviteInfo = await sendInvitations(alice.cells[0],getSampleInviteInput([bob.agentPubKey,carol.agentPubKey]));
+    assert.ok(invite_detail);
+
+    await dhtSync([alice, bob, carol], alice.cells[0].cell_id[0]);
+
+    console.log("\nCarol accepts, Bob cannot make it and proposes a later time\n")
+    await acceptInvite(carol.cells[0], invite_detail.creation_hash)
+    const start_time = Date.now() * 1000 + 86400000000
+    const proposal = await proposeNewTime(bob.cells[0], invite_detail.creation_hash, start_time, undefined, "the day after works for me")
+    await dhtSync([alice, bob, carol], alice.cells[0].cell_id[0]);
+
+    let alice_signal = await signalReceived_alice
+    assert.equal(alice_signal.payload['type'],'TimeProposed')
+    assert.equal(alice_signal.payload['proposal'].proposal.note, 'the day after works for me')
+
+    const proposals = await getTimeProposals(alice.cells[0], invite_detail.creation_hash)
+    assert.equal(proposals.length, 1)
+    assert.deepEqual(proposals[0].author, bob.agentPubKey)
+
+    console.log("\nAlice accepts the proposal, Carol's acceptance was for the old time and is reset\n")
+    const invite_detail_update: InviteInfo = await acceptProposal(alice.cells[0], proposal.proposal_hash)
+    assert.equal(invite_detail_update.invitation.start_time, start_time)
+    assert.isEmpty(invite_detail_update.invitees_who_accepted)
+    assert.equal(invite_detail_update.invitees_pending.length, 2)
+
+    await dhtSync([alice, bob, carol], carol.cells[0].cell_id[0]);
+    const invite_list_carol: InviteInfo[] = await getPendingInvites(carol.cells[0])
+    assert.equal(invite_list_carol.length, 1)
+  });
+});
+
+
+test('20. respond to single occurrences of a weekly Invite and cancel one of them', async () => {
+  await runScenario(async scenario => {
+    const testAppPath = process.cwd() + path_to_happ;
+
+    // Add 2 players with the test app to the Scenario.
+    const alice = await scenario.addPlayerWithApp({ path: testAppPath })
+    const bob = await scenario.addPlayerWithApp({ path: testAppPath })
+
+    await scenario.shareAllAgents();
+
+    console.log("\n************************* START TEST 20 ****************************\n")
+
+    console.log("\nAlice creates a weekly standup for Bob, four times\n")
+    const week = 7 * 86400000000
+    const start_time = Date.now() * 1000 + 86400000000
+    const invite_input = getSampleInviteInput([bob.agentPubKey])
+    invite_input.start_time = start_time
+    invite_input.end_time = start_time + 900000000
+    invite_input.recurrence = { frequency: "Weekly", interval: 1, by_day: [], count: 4 }
+    const invite_detail: InviteInfo = await sendInvitations(alice.cells[0], invite_input);
+    assert.ok(invite_detail);
+
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    console.log("\nBob accepts all occurrences but rejects the second one\n")
+    await acceptInvite(bob.cells[0], invite_detail.creation_hash)
+    await rejectInvite(bob.cells[0], invite_detail.creation_hash, undefined, "Travel", start_time + week)
+
+    console.log("\nAlice cancels the third occurrence and moves the fourth one a day later\n")
+    await addOccurrenceException(alice.cells[0], invite_detail.creation_hash, start_time + 2 * week, true)
+    await addOccurrenceException(alice.cells[0], invite_detail.creation_hash, start_time + 3 * week, false, start_time + 3 * week + 86400000000)
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    const occurrences = await getOccurrences(alice.cells[0], invite_detail.creation_hash, start_time, start_time + 10 * week)
+    assert.equal(occurrences.length, 4)
+    assert.deepEqual(occurrences[0].invitees_who_accepted, [bob.agentPubKey])
+    assert.deepEqual(occurrences[1].invitees_who_rejected, [bob.agentPubKey])
+    assert.isTrue(occurrences[2].cancelled)
+    assert.isTrue(occurrences[3].moved)
+    assert.equal(occurrences[3].end_time, start_time + 3 * week + 86400000000 + 900000000)
+
+    const invite_list_alice: InviteInfo[] = await getAllInvites(alice.cells[0])
+    assert.deepEqual(invite_list_alice[0].invitees_who_accepted, [bob.agentPubKey])
+  });
+});
+
+
+test('21. create an Invite from a template', async () => {
+  await runScenario(async scenario => {
+    const testAppPath = process.cwd() + path_to_happ;
+
+    // Add 2 players with the test app to the Scenario.
+    const alice = await scenario.addPlayerWithApp({ path: testAppPath })
+    const bob = await scenario.addPlayerWithApp({ path: testAppPath })
+
+    await scenario.shareAllAgents();
+
+    console.log("\n************************* START TEST 21 ****************************\n")
+
+    console.log("\nAlice creates a template for the weekly standup and changes its location\n")
+    const template = { name: "standup", invitees: [bob.agentPubKey], location: "London", duration: 900000000 }
+    const template_info = await createInviteTemplate(alice.cells[0], template)
+    await updateInviteTemplate(alice.cells[0], template_info.template_hash, { ...template, location: "Amsterdam" })
+
+    const templates = await getMyInviteTemplates(alice.cells[0])
+    assert.equal(templates.length, 1)
+    assert.equal(templates[0].template.location, "Amsterdam")
+
+    console.log("\nAlice creates this week's standup from the template\n")
+    const start_time = Date.now() * 1000
+    const invite_detail: InviteInfo = await createInvitationFromTemplate(alice.cells[0], template_info.template_hash, start_time)
+    assert.equal(invite_detail.invitation.location, "Amsterdam")
+    assert.equal(invite_detail.invitation.end_time, start_time + 900000000)
+
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+    const invite_list_bob: InviteInfo[] = await getPendingInvites(bob.cells[0])
+    assert.equal(invite_list_bob.length, 1)
+
+    console.log("\nAlice deletes the template\n")
+    await deleteInviteTemplate(alice.cells[0], template_info.template_hash)
+    assert.isEmpty(await getMyInviteTemplates(alice.cells[0]))
+  });
+});
+
+
+test('23. reach the quorum of an Invite', async () => {
+  await runScenario(async scenario => {
+
+    // setup signal receivers
+    let SignalHandler_bob: AppSignalCb | undefined;
+    let signalReceived_bob: Promise<AppSignal>
+    SignalHandler_bob = (signal) => {
+      console.log("signal found for Bob:",signal)
+      signalReceived_bob = new Promise<AppSignal>((resolve) => {
+        resolve(signal);
+      });
+    };
+
+    const testAppPath = process.cwd() + path_to_happ;
+
+    // Add 3 players with the test app to the Scenario.
+    const alice = await scenario.addPlayerWithApp({ path: testAppPath })
+    const bob = await scenario.addPlayerWithApp({ path: testAppPath })
+    const carol = await scenario.addPlayerWithApp({ path: testAppPath })
+
+    //workaround hack to get signals to work
+    const appWs_bob = await bob.conductor.connectAppWs(await bob.conductor.attachAppInterface())
+    appWs_bob.on("signal", SignalHandler_bob);
+
+    await scenario.shareAllAgents();
+
+    console.log("\n************************* START TEST 23 ****************************\n")
+
+    console.log("\nAlice creates an Invite that needs two attendees\n")
+    const invite_input = { ...getSampleInviteInput([bob.agentPubKey, carol.agentPubKey]), min_attendees: 2 }
+    const invite_detail: InviteInfo = await sendInvitations(alice.cells[0], invite_input);
+    assert.isFalse(invite_detail.quorum_reached)
+
+    await dhtSync([alice, bob, carol], alice.cells[0].cell_id[0]);
+
+    console.log("\nBob and then Carol accept\n")
+    await acceptInvite(bob.cells[0], invite_detail.creation_hash)
+    await dhtSync([alice, bob, carol], bob.cells[0].cell_id[0]);
+    await acceptInvite(carol.cells[0], invite_detail.creation_hash)
+    await dhtSync([alice, bob, carol], carol.cells[0].cell_id[0]);
+
+    let bob_signal = await signalReceived_bob
+    assert.equal(bob_signal.payload['type'], 'QuorumReached')
+    assert.isTrue(bob_signal.payload['data'].quorum_reached)
+
+    console.log("\nAn Invite whose rsvp deadline passed without its quorum did not happen\n")
+    const past_input = { ...getSampleInviteInput([bob.agentPubKey]), min_attendees: 1, rsvp_deadline: (Date.now() - 60000) * 1000 }
+    const past_detail: InviteInfo = await sendInvitations(alice.cells[0], past_input);
+    assert.isTrue(past_detail.did_not_happen)
+  });
+});
+
+
+test('24. join an open Invite with its invite code', async () => {
+  await runScenario(async scenario => {
+
+    const testAppPath = process.cwd() + path_to_happ;
+
+    // Add 3 players with the test app to the Scenario.
+    const alice = await scenario.addPlayerWithApp({ path: testAppPath })
+    const bob = await scenario.addPlayerWithApp({ path: testAppPath })
+    const carol = await scenario.addPlayerWithApp({ path: testAppPath })
+
+    await scenario.shareAllAgents();
+
+    console.log("\n************************* START TEST 24 ****************************\n")
+
+    console.log("\nAlice creates an open Invite that can be redeemed once\n")
+    const [key_pair, public_key] = await generateSigningKeyPair()
+    const invite_input = { ...getSampleInviteInput([]), invite_code: { public_key, max_redemptions: 1 } }
+    const invite_detail: InviteInfo = await sendInvitations(alice.cells[0], invite_input);
+
+    await dhtSync([alice, bob, carol], alice.cells[0].cell_id[0]);
+
+    console.log("\nBob redeems the code and accepts\n")
+    await sodium.ready
+    const bob_signature = sodium.crypto_sign_detached(bob.agentPubKey, key_pair.privateKey)
+    const redeemed_detail: InviteInfo = await redeemInviteCode(bob.cells[0], invite_detail.creation_hash, bob_signature)
+    assert.equal(encodeHashToBase64(redeemed_detail.invitees_redeemed[0]), encodeHashToBase64(bob.agentPubKey))
+
+    await acceptInvite(bob.cells[0], invite_detail.creation_hash)
+    await dhtSync([alice, bob, carol], bob.cells[0].cell_id[0]);
+
+    const pending_list_bob: InviteInfo[] = await getPendingInvites(bob.cells[0])
+    assert.isEmpty(pending_list_bob)
+    const invite_list_alice: InviteInfo[] = await getAllInvites(alice.cells[0])
+    assert.equal(encodeHashToBase64(invite_list_alice[0].invitees_who_accepted[0]), encodeHashToBase64(bob.agentPubKey))
+
+    console.log("\nCarol cannot redeem the code once it has been used up\n")
+    const carol_signature = sodium.crypto_sign_detached(carol.agentPubKey, key_pair.privateKey)
+    let redeem_error: any
+    try {
+      await redeemInviteCode(carol.cells[0], invite_detail.creation_hash, carol_signature)
+    } catch (e) {
+      redeem_error = e
+    }
+    assert.isDefined(redeem_error)
+
+    console.log("\nAlice rotates the code, the redemption of the old one no longer counts\n")
+    const [_, rotated_public_key] = await generateSigningKeyPair()
+    const rotated_detail: InviteInfo = await updateInvitation(alice.cells[0], { ...getSampleInviteInput([]), invite_code: { public_key: rotated_public_key }, creation_hash: invite_detail.creation_hash })
+    assert.isEmpty(rotated_detail.invitees_redeemed)
+    assert.isEmpty(rotated_detail.invitees_who_accepted)
+  });
+});
+
+
+test('25. invite a group and propagate its membership changes', async () => {
+  await runScenario(async scenario => {
+
+    const testAppPath = process.cwd() + path_to_happ;
+
+    // Add 3 players with the test app to the Scenario.
+    const alice = await scenario.addPlayerWithApp({ path: testAppPath })
+    const bob = await scenario.addPlayerWithApp({ path: testAppPath })
+    const carol = await scenario.addPlayerWithApp({ path: testAppPath })
+
+    await scenario.shareAllAgents();
+
+    console.log("\n************************* START TEST 25 ****************************\n")
+
+    console.log("\nAlice creates a group of Alice and Bob and invites the group\n")
+    const group_info = await createGroup(alice.cells[0], { name: "Team", members: [alice.agentPubKey, bob.agentPubKey] })
+    assert.equal((await getMyGroups(alice.cells[0])).length, 1)
+
+    const invite_input = { ...getSampleInviteInput([]), groups: [group_info.group_hash] }
+    const invite_detail: InviteInfo = await sendInvitations(alice.cells[0], invite_input);
+    assert.equal(invite_detail.invitation.invitees.length, 1)
+    assert.equal(encodeHashToBase64(invite_detail.invitation.invitees[0]), encodeHashToBase64(bob.agentPubKey))
+
+    await dhtSync([alice, bob, carol], alice.cells[0].cell_id[0]);
+    assert.equal((await getPendingInvites(bob.cells[0])).length, 1)
+
+    console.log("\nAlice replaces Bob by Carol in the group and propagates the change\n")
+    await updateGroup(alice.cells[0], group_info.group_hash, { name: "Team", members: [alice.agentPubKey, carol.agentPubKey] }, true)
+    await dhtSync([alice, bob, carol], alice.cells[0].cell_id[0]);
+
+    assert.isEmpty(await getPendingInvites(bob.cells[0]))
+    const invite_list_carol: InviteInfo[] = await getPendingInvites(carol.cells[0])
+    assert.equal(invite_list_carol.length, 1)
+    assert.equal(encodeHashToBase64(invite_list_carol[0].invitation.invitees[0]), encodeHashToBase64(carol.agentPubKey))
+
+    console.log("\nAlice adds Bob back to the group without propagating and updates the Invite, Bob is not invited again\n")
+    await updateGroup(alice.cells[0], group_info.group_hash, { name: "Team", members: [alice.agentPubKey, carol.agentPubKey, bob.agentPubKey] }, false)
+    const updated_detail: InviteInfo = await updateInvitation(alice.cells[0], { ...getSampleInviteInput([carol.agentPubKey]), location: "Paris", groups: [group_info.group_hash], creation_hash: invite_detail.creation_hash })
+    assert.equal(updated_detail.invitation.invitees.length, 1)
+    assert.equal(encodeHashToBase64(updated_detail.invitation.invitees[0]), encodeHashToBase64(carol.agentPubKey))
+  });
+});
+
+
+test('26. give invitees roles and tell required from optional attendees', async () => {
+  await runScenario(async scenario => {
+
+    const testAppPath = process.cwd() + path_to_happ;
+
+    // Add 3 players with the test app to the Scenario.
+    const alice = await scenario.addPlayerWithApp({ path: testAppPath })
+    const bob = await scenario.addPlayerWithApp({ path: testAppPath })
+    const carol = await scenario.addPlayerWithApp({ path: testAppPath })
+
+    await scenario.shareAllAgents();
+
+    console.log("\n************************* START TEST 26 ****************************\n")
+
+    console.log("\nAlice invites Bob as a required speaker and Carol as an optional volunteer\n")
+    const invite_input = {
+      ...getSampleInviteInput([bob.agentPubKey, carol.agentPubKey]),
+      invitee_roles: [
+        { agent: bob.agentPubKey, role: "speaker", required: true },
+        { agent: carol.agentPubKey, role: "volunteer", required: false },
+      ]
+    }
+    const invite_detail: InviteInfo = await sendInvitations(alice.cells[0], invite_input);
+    assert.equal(invite_detail.invitee_roles.length, 2)
+
+    await dhtSync([alice, bob, carol], alice.cells[0].cell_id[0]);
+
+    console.log("\nBob only finds the invitation among the speaker invitations\n")
+    assert.equal((await getPendingInvites(bob.cells[0], "speaker")).length, 1)
+    assert.isEmpty(await getPendingInvites(bob.cells[0], "volunteer"))
+
+    console.log("\nBob and Carol accept\n")
+    await acceptInvite(bob.cells[0], invite_detail.creation_hash)
+    await acceptInvite(carol.cells[0], invite_detail.creation_hash)
+    await dhtSync([alice, bob, carol], carol.cells[0].cell_id[0]);
+
+    const invite_list_alice: InviteInfo[] = await getAllInvites(alice.cells[0])
+    assert.equal(encodeHashToBase64(invite_list_alice[0].required_invitees_who_accepted[0]), encodeHashToBase64(bob.agentPubKey))
+    assert.equal(encodeHashToBase64(invite_list_alice[0].optional_invitees_who_accepted[0]), encodeHashToBase64(carol.agentPubKey))
+  });
+});
+
+
+test('27. poll the time slots of an Invite and finalize one', async () => {
+  await runScenario(async scenario => {
+
+    // setup signal receivers
+    let SignalHandler_bob: AppSignalCb | undefined;
+    let signalReceived_bob: Promise<AppSignal>
+    SignalHandler_bob = (signal) => {
+      console.log("signal found for Bob:",signal)
+      signalReceived_bob = new Promise<AppSignal>((resolve) => {
+        resolve(signal);
+      });
+    };
+
+    const testAppPath = process.cwd() + path_to_happ;
+
+    // Add 3 players with the test app to the Scenario.
+    const alice = await scenario.addPlayerWithApp({ path: testAppPath })
+    const bob = await scenario.addPlayerWithApp({ path: testAppPath })
+    const carol = await scenario.addPlayerWithApp({ path: testAppPath })
+
+    //workaround hack to get signals to work
+    const appWs_bob = await bob.conductor.connectAppWs(await bob.conductor.attachAppInterface())
+    appWs_bob.on("signal", SignalHandler_bob);
+
+    await scenario.shareAllAgents();
+
+    console.log("\n************************* START TEST 27 ****************************\n")
+
+    console.log("\nAlice creates an Invite polling two slots\n")
+    const first_slot = { start_time: (Date.now() + 86400000) * 1000, end_time: (Date.now() + 90000000) * 1000 }
+    const second_slot = { start_time: (Date.now() + 172800000) * 1000, end_time: (Date.now() + 176400000) * 1000 }
+    const invite_input = { ...getSampleInviteInput([bob.agentPubKey, carol.agentPubKey]), slots: [first_slot, second_slot] }
+    const invite_detail: InviteInfo = await sendInvitations(alice.cells[0], invite_input);
+    assert.equal(invite_detail.slot_tallies.length, 2)
+
+    await dhtSync([alice, bob, carol], alice.cells[0].cell_id[0]);
+
+    console.log("\nBob and Carol vote on the slots\n")
+    await voteOnSlot(bob.cells[0], invite_detail.creation_hash, first_slot, "no")
+    await voteOnSlot(bob.cells[0], invite_detail.creation_hash, second_slot, "yes")
+    await voteOnSlot(carol.cells[0], invite_detail.creation_hash, first_slot, "maybe")
+    await voteOnSlot(carol.cells[0], invite_detail.creation_hash, second_slot, "yes")
+    await dhtSync([alice, bob, carol], carol.cells[0].cell_id[0]);
+
+    const invite_list_alice: InviteInfo[] = await getAllInvites(alice.cells[0])
+    const tallies = invite_list_alice[0].slot_tallies
+    assert.equal(tallies[0].no.length, 1)
+    assert.equal(tallies[0].maybe.length, 1)
+    assert.equal(tallies[1].yes.length, 2)
+
+    console.log("\nAlice finalizes the second slot\n")
+    const finalized_detail: InviteInfo = await finalizeSlot(alice.cells[0], invite_detail.creation_hash, second_slot)
+    assert.equal(finalized_detail.invitation.start_time, second_slot.start_time)
+    assert.equal(finalized_detail.invitation.end_time, second_slot.end_time)
+
+    await dhtSync([alice, bob, carol], alice.cells[0].cell_id[0]);
+    let bob_signal = await signalReceived_bob
+    assert.equal(bob_signal.payload['type'], 'SlotFinalized')
+  });
+});
+
+
+test('28. encrypt the location and details of an Invite for its invitees', async () => {
+  await runScenario(async scenario => {
+
+    const testAppPath = process.cwd() + path_to_happ;
+
+    // Add 2 players with the test app to the Scenario.
+    const alice = await scenario.addPlayerWithApp({ path: testAppPath })
+    const bob = await scenario.addPlayerWithApp({ path: testAppPath })
+
+    await scenario.shareAllAgents();
+
+    console.log("\n************************* START TEST 28 ****************************\n")
+
+    console.log("\nBob publishes an encryption key\n")
+    await publishEncryptionKey(bob.cells[0])
+    await dhtSync([alice, bob], bob.cells[0].cell_id[0]);
+
+    console.log("\nAlice creates an Invite with an encrypted location\n")
+    const invite_input = { ...getSampleInviteInput([bob.agentPubKey]), details: { room: "42" }, encrypt_details: true }
+    const invite_detail: InviteInfo = await sendInvitations(alice.cells[0], invite_input);
+    assert.equal(invite_detail.invitation.encrypted_details?.recipients.length, 2)
+    assert.equal(invite_detail.invitation.location, "London")
+
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    console.log("\nBob reads the decrypted location\n")
+    const invite_list_bob: InviteInfo[] = await getPendingInvites(bob.cells[0])
+    assert.equal(invite_list_bob[0].invitation.location, "London")
+    assert.equal(invite_list_bob[0].invitation.details?.room, "42")
+
+    console.log("\nAlice changes the room without asking for encryption, the details stay encrypted\n")
+    const invite_update = { ...getSampleInviteInput([bob.agentPubKey]), details: { room: "43" }, creation_hash: invite_detail.creation_hash }
+    const invite_detail_update: InviteInfo = await updateInvitation(alice.cells[0], invite_update);
+    assert.equal(invite_detail_update.invitation.encrypted_details?.recipients.length, 2)
+    assert.equal(invite_detail_update.invitation.details?.room, "43")
+
+    console.log("\nAlice turns the encryption off\n")
+    const invite_detail_plain: InviteInfo = await updateInvitation(alice.cells[0], { ...invite_update, encrypt_details: false });
+    assert.isNull(invite_detail_plain.invitation.encrypted_details)
+    assert.equal(invite_detail_plain.invitation.details?.room, "43")
+  });
+});
+
+
+test('29. countersign the acceptance of an Invite', async () => {
+  await runScenario(async scenario => {
+
+    const testAppPath = process.cwd() + path_to_happ;
+
+    // Add 2 players with the test app to the Scenario.
+    const alice = await scenario.addPlayerWithApp({ path: testAppPath })
+    const bob = await scenario.addPlayerWithApp({ path: testAppPath })
+
+    await scenario.shareAllAgents();
+
+    console.log("\n************************* START TEST 29 ****************************\n")
+
+    console.log("\nAlice creates an Invite whose acceptances are countersigned\n")
+    const invite_input = { ...getSampleInviteInput([bob.agentPubKey]), countersign_acceptances: true }
+    const invite_detail: InviteInfo = await sendInvitations(alice.cells[0], invite_input);
+
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    console.log("\nBob countersigns the acceptance with Alice and then accepts\n")
+    const acceptance_hash: ActionHash = await countersignAcceptance(bob.cells[0], invite_detail.creation_hash)
+
+    //the session completes once both signatures have been published
+    await new Promise((resolve) => setTimeout(resolve, 5000));
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    await acceptInvite(bob.cells[0], invite_detail.creation_hash, 0, undefined, undefined, undefined, acceptance_hash)
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    const invite_list_alice: InviteInfo[] = await getAllInvites(alice.cells[0])
+    assert.isTrue(invite_list_alice[0].invitee_responses[0].countersigned)
+  });
+});
+
+
+test('30. check in to an Invite with a check-in token', async () => {
+  await runScenario(async scenario => {
+
+    const testAppPath = process.cwd() + path_to_happ;
+
+    // Add 2 players with the test app to the Scenario.
+    const alice = await scenario.addPlayerWithApp({ path: testAppPath })
+    const bob = await scenario.addPlayerWithApp({ path: testAppPath })
+
+    await scenario.shareAllAgents();
+
+    console.log("\n************************* START TEST 30 ****************************\n")
+
+    console.log("\nAlice creates an Invite that is taking place and requires a check-in token\n")
+    const now = Date.now() * 1000
+    const invite_input = {
+      ...getSampleInviteInput([bob.agentPubKey]),
+      start_time: now - 60 * 1000 * 1000,
+      end_time: now + 60 * 60 * 1000 * 1000,
+      require_check_in_token: true
+    }
+    const invite_detail: InviteInfo = await sendInvitations(alice.cells[0], invite_input);
+
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    console.log("\nBob cannot check in without a token\n")
+    let check_in_error: any
+    try {
+      await checkIn(bob.cells[0], invite_detail.creation_hash)
+    } catch (e) {
+      check_in_error = e
+    }
+    assert.isDefined(check_in_error)
+
+    console.log("\nAlice issues a token to Bob, who checks in with it\n")
+    const token = await issueCheckInToken(alice.cells[0], invite_detail.creation_hash, bob.agentPubKey)
+    await checkIn(bob.cells[0], invite_detail.creation_hash, token)
+
+    await dhtSync([alice, bob], bob.cells[0].cell_id[0]);
+
+    const invite_list_alice: InviteInfo[] = await getAllInvites(alice.cells[0])
+    assert.equal(invite_list_alice[0].invitees_checked_in.length, 1)
+    assert.equal(encodeHashToBase64(invite_list_alice[0].invitees_checked_in[0]), encodeHashToBase64(bob.agentPubKey))
+  });
+});
+
+
+test('31. get a reminder before an accepted Invite starts', async () => {
+  await runScenario(async scenario => {
+
+    // setup signal receiver for the reminder only
+    let resolveReminder_bob: (signal: AppSignal) => void
+    const reminderReceived_bob = new Promise<AppSignal>((resolve) => {
+      resolveReminder_bob = resolve
+    });
+    const SignalHandler_bob: AppSignalCb = (signal) => {
+      console.log("signal found for bob:",signal)
+      if (signal.payload['type'] === 'InvitationReminder') {
+        resolveReminder_bob(signal)
+      }
+    };
+
+    const testAppPath = process.cwd() + path_to_happ;
+
+    // Add 2 players with the test app to the Scenario.
+    const alice = await scenario.addPlayerWithApp({ path: testAppPath })
+    const bob = await scenario.addPlayerWithApp({ path: testAppPath })
+
+    //workaround hack to get signals to work
+    const appWs_bob = await bob.conductor.connectAppWs(await bob.conductor.attachAppInterface())
+    appWs_bob.on("signal", SignalHandler_bob);
+
+    await scenario.shareAllAgents();
+
+    console.log("\n************************* START TEST 31 ****************************\n")
+
+    console.log("\nBob keeps the default reminders, then only wants the one 15 minutes before\n")
+    const fifteen_minutes = 15 * 60 * 1000 * 1000
+    assert.deepEqual(await getReminderOffsets(bob.cells[0]), [24 * 60 * 60 * 1000 * 1000, fifteen_minutes])
+    await setReminderOffsets(bob.cells[0], [fifteen_minutes])
+    assert.deepEqual(await getReminderOffsets(bob.cells[0]), [fifteen_minutes])
+
+    console.log("\nAlice creates an Invite starting in 10 minutes and Bob accepts it\n")
+    const now = Date.now() * 1000
+    const invite_input = {
+      ...getSampleInviteInput([bob.agentPubKey]),
+      start_time: now + 10 * 60 * 1000 * 1000,
+      end_time: now + 70 * 60 * 1000 * 1000
+    }
+    const invite_detail: InviteInfo = await sendInvitations(alice.cells[0], invite_input);
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+    await acceptInvite(bob.cells[0], invite_detail.creation_hash)
+
+    console.log("\nBob is reminded by the scheduler within a minute or two\n")
+    const reminder = await reminderReceived_bob
+    assert.equal(encodeHashToBase64(reminder.payload['data'].creation_hash), encodeHashToBase64(invite_detail.creation_hash))
+    assert.equal(reminder.payload['offset'], fifteen_minutes)
+  });
+});
+
+
+test('32. catch up on notifications from the inbox', async () => {
+  await runScenario(async scenario => {
+
+    const testAppPath = process.cwd() + path_to_happ;
+
+    // Add 2 players with the test app to the Scenario, nobody listens to signals
+    const alice = await scenario.addPlayerWithApp({ path: testAppPath })
+    const bob = await scenario.addPlayerWithApp({ path: testAppPath })
+
+    await scenario.shareAllAgents();
+
+    console.log("\n************************* START TEST 32 ****************************\n")
+
+    console.log("\nAlice creates an Invite to Bob, who finds it in the inbox\n")
+    const invite_detail: InviteInfo = await sendInvitations(alice.cells[0], getSampleInviteInput([bob.agentPubKey]));
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    const notifications_bob = await getNotifications(bob.cells[0])
+    assert.equal(notifications_bob.length, 1)
+    assert.equal(notifications_bob[0].kind, "InvitationReceived")
+    assert.equal(encodeHashToBase64(notifications_bob[0].creation_hash), encodeHashToBase64(invite_detail.creation_hash))
+    assert.equal(encodeHashToBase64(notifications_bob[0].sender), encodeHashToBase64(alice.agentPubKey))
+    assert.isFalse(notifications_bob[0].read)
+    assert.equal(await getUnreadNotificationCount(bob.cells[0]), 1)
+
+    console.log("\nBob reads the notification\n")
+    await markNotificationRead(bob.cells[0], notifications_bob[0].notification_hash)
+    assert.equal(await getUnreadNotificationCount(bob.cells[0]), 0)
+    assert.isTrue((await getNotifications(bob.cells[0]))[0].read)
+
+    console.log("\nBob accepts and Alice finds the acceptance in the inbox\n")
+    await acceptInvite(bob.cells[0], invite_detail.creation_hash)
+    await dhtSync([alice, bob], bob.cells[0].cell_id[0]);
+
+    const notifications_alice = await getNotifications(alice.cells[0])
+    assert.isTrue(notifications_alice.some((notification) => notification.kind === "InvitationAccepted"))
+    assert.isAbove(await getUnreadNotificationCount(alice.cells[0]), 0)
+  });
+});
+
+
+test('33. see which invitees the Invite was delivered to', async () => {
+  await runScenario(async scenario => {
+
+    const testAppPath = process.cwd() + path_to_happ;
+
+    // Add 2 players with the test app to the Scenario.
+    const alice = await scenario.addPlayerWithApp({ path: testAppPath })
+    const bob = await scenario.addPlayerWithApp({ path: testAppPath })
+
+    await scenario.shareAllAgents();
+
+    console.log("\n************************* START TEST 33 ****************************\n")
+
+    console.log("\nAlice creates an Invite to Bob, who acknowledges it\n")
+    const invite_detail: InviteInfo = await sendInvitations(alice.cells[0], getSampleInviteInput([bob.agentPubKey]));
+    assert.isNull(invite_detail.invitees_delivered)
+
+    //the invitation is sent from post_commit, after create_invitation has returned
+    let invite_list_alice: InviteInfo[] = []
+    for (let attempt = 0; attempt < 10; attempt++) {
+      await new Promise((resolve) => setTimeout(resolve, 1000));
+      invite_list_alice = await getAllInvites(alice.cells[0])
+      if (invite_list_alice[0].invitees_delivered && invite_list_alice[0].invitees_delivered.length > 0) {
+        break
+      }
+    }
+    assert.equal(invite_list_alice[0].invitees_delivered!.length, 1)
+    assert.equal(encodeHashToBase64(invite_list_alice[0].invitees_delivered![0]), encodeHashToBase64(bob.agentPubKey))
+
+    console.log("\nOnly the host that sent the Invite knows about its delivery\n")
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+    const invite_list_bob: InviteInfo[] = await getAllInvites(bob.cells[0])
+    assert.isNull(invite_list_bob[0].invitees_delivered)
+  });
+});
+
+
+test('34. the UI of the acting agent gets a local signal', async () => {
+  await runScenario(async scenario => {
+
+    // setup signal receivers that keep the first InvitationReceived signal
+    let resolveReceived_alice: (signal: AppSignal) => void
+    const receivedSignal_alice = new Promise<AppSignal>((resolve) => {
+      resolveReceived_alice = resolve
+    });
+    const localSignals_alice: AppSignal[] = []
+    const SignalHandler_alice: AppSignalCb = (signal) => {
+      console.log("signal found for Alice:",signal)
+      if (signal.payload['local']) {
+        localSignals_alice.push(signal)
+      }
+      if (signal.payload['type'] === 'InvitationReceived') {
+        resolveReceived_alice(signal)
+      }
+    };
+
+    let resolveReceived_bob: (signal: AppSignal) => void
+    const receivedSignal_bob = new Promise<AppSignal>((resolve) => {
+      resolveReceived_bob = resolve
+    });
+    const SignalHandler_bob: AppSignalCb = (signal) => {
+      console.log("signal found for bob:",signal)
+      if (signal.payload['type'] === 'InvitationReceived') {
+        resolveReceived_bob(signal)
+      }
+    };
+
+    const testAppPath = process.cwd() + path_to_happ;
+
+    // Add 2 players with the test app to the Scenario.
+    const alice = await scenario.addPlayerWithApp({ path: testAppPath })
+    const bob = await scenario.addPlayerWithApp({ path: testAppPath })
+
+    //workaround hack to get signals to work
+    const appWs_alice = await alice.conductor.connectAppWs(await alice.conductor.attachAppInterface())
+    appWs_alice.on("signal", SignalHandler_alice);
+
+    const appWs_bob = await bob.conductor.connectAppWs(await bob.conductor.attachAppInterface())
+    appWs_bob.on("signal", SignalHandler_bob);
+
+    await scenario.shareAllAgents();
+
+    console.log("\n************************* START TEST 34 ****************************\n")
+
+    console.log("\nAlice creates an Invite to Bob and both UIs are signalled\n")
+    const invite_detail: InviteInfo = await sendInvitations(alice.cells[0], getSampleInviteInput([bob.agentPubKey]));
+
+    const alice_signal = await receivedSignal_alice
+    assert.isTrue(alice_signal.payload['local'])
+    assert.equal(encodeHashToBase64(alice_signal.payload['data'].creation_hash), encodeHashToBase64(invite_detail.creation_hash))
+
+    const bob_signal = await receivedSignal_bob
+    assert.isFalse(bob_signal.payload['local'])
+    assert.equal(encodeHashToBase64(bob_signal.payload['data'].creation_hash), encodeHashToBase64(invite_detail.creation_hash))
+    assert.equal(localSignals_alice.length, 1)
+
+    console.log("\nAlice removes Bob, the UI of Alice gets only the update while Bob is sent the revocation\n")
+    await updateInvitation(alice.cells[0], getSampleInviteInputUpdate([alice.agentPubKey], invite_detail.creation_hash))
+    await new Promise((resolve) => setTimeout(resolve, 5000));
+    assert.deepEqual(localSignals_alice.map(signal => signal.payload['type']), ['InvitationReceived', 'InvitationUpdated'])
+  });
+});
+
+
+test('37. check in to the occurrence of a recurring Invite that is taking place', async () => {
+  await runScenario(async scenario => {
+
+    const testAppPath = process.cwd() + path_to_happ;
+
+    // Add 2 players with the test app to the Scenario.
+    const alice = await scenario.addPlayerWithApp({ path: testAppPath })
+    const bob = await scenario.addPlayerWithApp({ path: testAppPath })
+
+    await scenario.shareAllAgents();
+
+    console.log("\n************************* START TEST 37 ****************************\n")
+
+    console.log("\nAlice creates a daily Invite that started two days ago, today's occurrence is taking place\n")
+    const day = 24 * 60 * 60 * 1000 * 1000
+    const now = Date.now() * 1000
+    const first_start = now - 2 * day - 60 * 1000 * 1000
+    const invite_input = {
+      ...getSampleInviteInput([bob.agentPubKey]),
+      start_time: first_start,
+      end_time: first_start + 60 * 60 * 1000 * 1000,
+      recurrence: { frequency: "Daily" as const, interval: 1, by_day: [] }
+    }
+    const invite_detail: InviteInfo = await sendInvitations(alice.cells[0], invite_input);
+
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    console.log("\nBob cannot check in to the first occurrence, which is over\n")
+    let check_in_error: any
+    try {
+      await checkIn(bob.cells[0], invite_detail.creation_hash, undefined, first_start)
+    } catch (e) {
+      check_in_error = e
+    }
+    assert.isDefined(check_in_error)
+
+    console.log("\nBob checks in to the occurrence taking place\n")
+    await checkIn(bob.cells[0], invite_detail.creation_hash)
+
+    await dhtSync([alice, bob], bob.cells[0].cell_id[0]);
+
+    const occurrences = await getOccurrences(alice.cells[0], invite_detail.creation_hash, first_start, now)
+    assert.equal(occurrences.length, 3)
+    assert.isEmpty(occurrences[0].invitees_checked_in)
+    assert.equal(encodeHashToBase64(occurrences[2].invitees_checked_in[0]), encodeHashToBase64(bob.agentPubKey))
+  });
+});
@@ -4,7 +4,7 @@ import { runScenario, pause, CallableCell, dhtSync, runLocalServices, createCond
 import { NewEntryAction, ActionHash, Record, AppBundleSource, fakeDnaHash, fakeActionHash, fakeAgentPubKey, fakeEntryHash, AppSignalCb, AppSignal, RecordEntry, AppWebsocket } from '@holochain/client';
 import { decode } from '@msgpack/msgpack';
 
-import { acceptInvite, clearInvite, getAllInvites, getPendingInvites, getSampleInviteInput, getSampleInviteInputUpdate, InviteInfo, rejectInvite, sendInvitations, updateInvitation } from './common.js';
+import { acceptInvite, cancelInvite, clearInvite, closeInvite, getAllInvites, getPendingInvites, getSampleInviteInput, getSampleInviteInputUpdate, InviteInfo, rejectInvite, sendInvitations, updateInvitation } from './common.js';
 
 const path_to_happ = '/../workdir/happ/invitations.happ'
 
@@ -89,3 +89,364 @@ test('8. try to accept an invite without being an invitee', async () => {
   });
 });
 
+test('10. try to cancel an invite without being the author', async () => {
+  await runScenario(async scenario => {
+    const testAppPath = process.cwd() + path_to_happ;
+
+    // Set up the app to be installed 
+    const appSource_alice = { appBundleSource: { path: testAppPath }}
+    const appSource_bob = { appBundleSource: { path: testAppPath }}
+
+    const [alice,bob] = await scenario.addPlayersWithApps([appSource_alice,appSource_bob]);
+
+    await scenario.shareAllAgents();
+    console.log("\n************************* START TEST 10 ****************************\n")
+
+    console.log("\nAlice creates an Invite")
+    const invite_detail: InviteInfo = await sendInvitations(alice.cells[0], getSampleInviteInput([bob.agentPubKey,alice.agentPubKey]));
+    assert.ok(invite_detail);
+
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    console.log("Bob trys to cancel the invite")
+    var result : null | ActionHash = null
+    try {
+      var result: ActionHash = await cancelInvite(bob.cells[0],invite_detail.creation_hash)
+    } catch (e:any){
+      console.log(e)
+    }
+    assert.isNull(result)
+  });
+});
+
+test('14. try to accept an invite after its rsvp deadline', async () => {
+  await runScenario(async scenario => {
+    const testAppPath = process.cwd() + path_to_happ;
+
+    // Set up the app to be installed 
+    const appSource_alice = { appBundleSource: { path: testAppPath }}
+    const appSource_bob = { appBundleSource: { path: testAppPath }}
+
+    const [alice,bob] = await scenario.addPlayersWithApps([appSource_alice,appSource_bob]);
+
+    await scenario.shareAllAgents();
+    console.log("\n************************* START TEST 14 ****************************\n")
+
+    console.log("\nAlice creates an Invite with an rsvp deadline in the past")
+    const invite_input = { ...getSampleInviteInput([bob.agentPubKey]), rsvp_deadline: (Date.now() - 60000) * 1000 }
+    const invite_detail: InviteInfo = await sendInvitations(alice.cells[0], invite_input);
+    assert.ok(invite_detail);
+    assert.isTrue(invite_detail.expired)
+
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    console.log("Bob does not see the expired invite in his pending invites")
+    const invite_list_bob = await getPendingInvites(bob.cells[0])
+    assert.isNull(invite_list_bob)
+
+    console.log("Bob trys to accept the invite, validation rejects the response")
+    var result : null | ActionHash = null
+    try {
+      var result: ActionHash = await acceptInvite(bob.cells[0],invite_detail.creation_hash)
+    } catch (e:any){
+      console.log(e)
+    }
+    assert.isNull(result)
+  });
+});
+
+test('16. try to bring more guests than an invite allows', async () => {
+  await runScenario(async scenario => {
+    const testAppPath = process.cwd() + path_to_happ;
+
+    // Set up the app to be installed 
+    const appSource_alice = { appBundleSource: { path: testAppPath }}
+    const appSource_bob = { appBundleSource: { path: testAppPath }}
+
+    const [alice,bob] = await scenario.addPlayersWithApps([appSource_alice,appSource_bob]);
+
+    await scenario.shareAllAgents();
+    console.log("\n************************* START TEST 16 ****************************\n")
+
+    console.log("\nAlice creates an Invite allowing two guests per invitee")
+    const invite_input = { ...getSampleInviteInput([bob.agentPubKey]), allow_guests: true, max_guests_per_invitee: 2 }
+    const invite_detail: InviteInfo = await sendInvitations(alice.cells[0], invite_input);
+    assert.ok(invite_detail);
+
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    console.log("Bob trys to accept the invite with three guests")
+    var result : null | ActionHash = null
+    try {
+      var result: ActionHash = await acceptInvite(bob.cells[0],invite_detail.creation_hash, 3)
+    } catch (e:any){
+      console.log(e)
+    }
+    assert.isNull(result)
+
+    console.log("Bob accepts the invite with two guests")
+    await acceptInvite(bob.cells[0],invite_detail.creation_hash, 2, "bringing my kids")
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    const invite_list_alice: InviteInfo[] = await getAllInvites(alice.cells[0])
+    assert.equal(invite_list_alice[0].headcount, 3)
+  });
+});
+
+
+test('19. a co-host updates an invite but cannot change its co-hosts', async () => {
+  await runScenario(async scenario => {
+    const testAppPath = process.cwd() + path_to_happ;
+
+    // Add 3 players with the test app to the Scenario.
+    const alice = await scenario.addPlayerWithApp({ path: testAppPath })
+    const bob = await scenario.addPlayerWithApp({ path: testAppPath })
+    const carol = await scenario.addPlayerWithApp({ path: testAppPath })
+
+    await scenario.shareAllAgents();
+    console.log("\n************************* START TEST 19 ****************************\n")
+
+    console.log("\nAlice creates an Invite to Carol with Bob as co-host")
+    const invite_input = getSampleInviteInput([carol.agentPubKey])
+    invite_input.co_hosts = [bob.agentPubKey]
+    const invite_detail: InviteInfo = await sendInvitations(alice.cells[0], invite_input);
+    assert.ok(invite_detail);
+
+    await dhtSync([alice, bob, carol], alice.cells[0].cell_id[0]);
+
+    console.log("Bob sees the invite in the list of all invites through the inviter link")
+    const invite_list_bob: InviteInfo[] = await getAllInvites(bob.cells[0])
+    assert.equal(invite_list_bob.length, 1)
+
+    console.log("Bob updates the location of the invite")
+    const invite_update = getSampleInviteInputUpdate([carol.agentPubKey], invite_detail.creation_hash)
+    invite_update.co_hosts = [bob.agentPubKey]
+    const invite_detail_update: InviteInfo = await updateInvitation(bob.cells[0], invite_update)
+    assert.equal(invite_detail_update.invitation.location, "Amsterdam")
+    assert.deepEqual(invite_detail_update.author, alice.agentPubKey)
+
+    await dhtSync([alice, bob, carol], bob.cells[0].cell_id[0]);
+
+    console.log("Bob tries to add Carol as a co-host")
+    invite_update.co_hosts = [bob.agentPubKey, carol.agentPubKey]
+    var co_host_update: null | InviteInfo = null
+    try {
+      co_host_update = await updateInvitation(bob.cells[0], invite_update)
+    } catch (e:any){
+      console.log(e)
+    }
+    assert.isNull(co_host_update)
+  });
+});
+
+
+test('22. try to accept an invite after it was closed', async () => {
+  await runScenario(async scenario => {
+    const testAppPath = process.cwd() + path_to_happ;
+
+    // setup signal receivers
+    let SignalHandler_bob: AppSignalCb | undefined;
+    let signalReceived_bob: Promise<AppSignal>
+    SignalHandler_bob = (signal) => {
+      console.log("signal found for Bob:",signal)
+      signalReceived_bob = new Promise<AppSignal>((resolve) => {
+        resolve(signal);
+      });
+    };
+
+    const appSource_alice = { appBundleSource: { path: testAppPath }}
+    const appSource_bob = { appBundleSource: { path: testAppPath }}
+
+    const [alice,bob] = await scenario.addPlayersWithApps([appSource_alice,appSource_bob]);
+
+    //workaround hack to get signals to work
+    const appWs_bob = await bob.conductor.connectAppWs(await bob.conductor.attachAppInterface())
+    appWs_bob.on("signal", SignalHandler_bob);
+
+    await scenario.shareAllAgents();
+    console.log("\n************************* START TEST 22 ****************************\n")
+
+    console.log("\nAlice creates an Invite and closes it")
+    const invite_detail: InviteInfo = await sendInvitations(alice.cells[0], getSampleInviteInput([bob.agentPubKey]));
+    assert.ok(invite_detail);
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    const closed_detail: InviteInfo = await closeInvite(alice.cells[0], invite_detail.creation_hash)
+    assert.isTrue(closed_detail.invitation.closed)
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    let bob_signal = await signalReceived_bob
+    assert.equal(bob_signal.payload['type'], 'InvitationClosed')
+
+    console.log("Bob does not see the closed invite in the pending invites")
+    assert.isNull(await getPendingInvites(bob.cells[0]))
+
+    console.log("Bob trys to accept the invite")
+    var result : null | ActionHash = null
+    try {
+      result = await acceptInvite(bob.cells[0],invite_detail.creation_hash)
+    } catch (e:any){
+      console.log(e)
+    }
+    assert.isNull(result)
+  });
+});
+
+
+test('35. responses given before the close stay locked in', async () => {
+  await runScenario(async scenario => {
+    const testAppPath = process.cwd() + path_to_happ;
+
+    const appSource_alice = { appBundleSource: { path: testAppPath }}
+    const appSource_bob = { appBundleSource: { path: testAppPath }}
+
+    const [alice,bob] = await scenario.addPlayersWithApps([appSource_alice,appSource_bob]);
+
+    await scenario.shareAllAgents();
+    console.log("\n************************* START TEST 35 ****************************\n")
+
+    console.log("\nAlice creates an Invite, Bob accepts it and Alice closes it")
+    const invite_detail: InviteInfo = await sendInvitations(alice.cells[0], getSampleInviteInput([bob.agentPubKey]));
+    assert.ok(invite_detail);
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    await acceptInvite(bob.cells[0], invite_detail.creation_hash)
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    const closed_detail: InviteInfo = await closeInvite(alice.cells[0], invite_detail.creation_hash)
+    assert.isTrue(closed_detail.invitation.closed)
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    console.log("Alice trys to update the closed invite, validation rejects it")
+    var update_result : null | InviteInfo = null
+    try {
+      update_result = await updateInvitation(alice.cells[0], getSampleInviteInputUpdate([bob.agentPubKey], invite_detail.creation_hash))
+    } catch (e:any){
+      console.log(e)
+    }
+    assert.isNull(update_result)
+
+    console.log("Bob trys to change the response to rejected")
+    var result : null | ActionHash = null
+    try {
+      result = await rejectInvite(bob.cells[0], invite_detail.creation_hash)
+    } catch (e:any){
+      console.log(e)
+    }
+    assert.isNull(result)
+
+    console.log("Alice still counts the acceptance from before the close")
+    const invite_list_alice: InviteInfo[] = await getAllInvites(alice.cells[0])
+    assert.deepEqual(invite_list_alice[0].invitees_who_accepted, [bob.agentPubKey])
+  });
+});
+
+
+test('36. try to change the rsvp deadline of an invite in an update', async () => {
+  await runScenario(async scenario => {
+    const testAppPath = process.cwd() + path_to_happ;
+
+    const appSource_alice = { appBundleSource: { path: testAppPath }}
+    const appSource_bob = { appBundleSource: { path: testAppPath }}
+
+    const [alice,bob] = await scenario.addPlayersWithApps([appSource_alice,appSource_bob]);
+
+    await scenario.shareAllAgents();
+    console.log("\n************************* START TEST 36 ****************************\n")
+
+    console.log("\nAlice creates an Invite without an rsvp deadline")
+    const invite_detail: InviteInfo = await sendInvitations(alice.cells[0], getSampleInviteInput([bob.agentPubKey]));
+    assert.ok(invite_detail);
+
+    console.log("Alice trys to add a deadline in the past, validation rejects it")
+    var result : null | InviteInfo = null
+    try {
+      result = await updateInvitation(alice.cells[0], { ...getSampleInviteInput([bob.agentPubKey]), rsvp_deadline: (Date.now() - 60000) * 1000, creation_hash: invite_detail.creation_hash })
+    } catch (e:any){
+      console.log(e)
+    }
+    assert.isNull(result)
+
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    console.log("Bob can still accept the invite")
+    const response_hash: ActionHash = await acceptInvite(bob.cells[0], invite_detail.creation_hash)
+    assert.ok(response_hash)
+  });
+});
+
+test('38. try to accept an invite that countersigns its acceptances without an acceptance', async () => {
+  await runScenario(async scenario => {
+    const testAppPath = process.cwd() + path_to_happ;
+
+    const appSource_alice = { appBundleSource: { path: testAppPath }}
+    const appSource_bob = { appBundleSource: { path: testAppPath }}
+
+    const [alice,bob] = await scenario.addPlayersWithApps([appSource_alice,appSource_bob]);
+
+    await scenario.shareAllAgents();
+    console.log("\n************************* START TEST 38 ****************************\n")
+
+    console.log("\nAlice creates an Invite whose acceptances are countersigned")
+    const invite_input = { ...getSampleInviteInput([bob.agentPubKey]), countersign_acceptances: true }
+    const invite_detail: InviteInfo = await sendInvitations(alice.cells[0], invite_input);
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    console.log("Bob trys to accept without countersigning an acceptance first, validation rejects it")
+    var result : null | ActionHash = null
+    try {
+      result = await acceptInvite(bob.cells[0], invite_detail.creation_hash)
+    } catch (e:any){
+      console.log(e)
+    }
+    assert.isNull(result)
+  });
+});
+
+test('39. respond to a far-future occurrence and try to recur too often', async () => {
+  await runScenario(async scenario => {
+    const testAppPath = process.cwd() + path_to_happ;
+
+    const appSource_alice = { appBundleSource: { path: testAppPath }}
+    const appSource_bob = { appBundleSource: { path: testAppPath }}
+
+    const [alice,bob] = await scenario.addPlayersWithApps([appSource_alice,appSource_bob]);
+
+    await scenario.shareAllAgents();
+    console.log("\n************************* START TEST 39 ****************************\n")
+
+    console.log("\nAlice creates an Invite that recurs daily without an end")
+    const day = 24 * 60 * 60 * 1000 * 1000
+    const first_start = Date.now() * 1000
+    const invite_input = {
+      ...getSampleInviteInput([bob.agentPubKey]),
+      start_time: first_start,
+      end_time: first_start + 60 * 60 * 1000 * 1000,
+      recurrence: { frequency: "Daily" as const, interval: 1, by_day: [] }
+    }
+    const invite_detail: InviteInfo = await sendInvitations(alice.cells[0], invite_input);
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    console.log("Bob accepts the occurrence in 100000 days, validation only expands the period it falls in")
+    const response_hash: ActionHash = await acceptInvite(bob.cells[0], invite_detail.creation_hash, 0, undefined, undefined, first_start + 100000 * day)
+    assert.ok(response_hash)
+
+    console.log("Bob trys to accept a far-future time that is not an occurrence")
+    var result : null | ActionHash = null
+    try {
+      result = await acceptInvite(bob.cells[0], invite_detail.creation_hash, 0, undefined, undefined, first_start + 100000 * day + 1)
+    } catch (e:any){
+      console.log(e)
+    }
+    assert.isNull(result)
+
+    console.log("Alice trys to create an Invite that recurs more often than allowed")
+    var invite_result : null | InviteInfo = null
+    try {
+      invite_result = await sendInvitations(alice.cells[0], { ...invite_input, recurrence: { frequency: "Daily" as const, interval: 1, by_day: [], count: 1001 } })
+    } catch (e:any){
+      console.log(e)
+    }
+    assert.isNull(invite_result)
+  });
+});